@@ -0,0 +1,83 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::{hint::black_box, thread, time::Instant};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+type AtomicTagPtr = tagptr::AtomicTagPtr<u64, 3>;
+type TagPtr = tagptr::TagPtr<u64, 3>;
+
+fn compose_decompose(c: &mut Criterion) {
+    let reference = &mut 1;
+    let raw: *mut u64 = reference;
+    c.bench_function("compose + decompose", |b| {
+        b.iter(|| TagPtr::compose(black_box(raw), black_box(0b101)).decompose())
+    });
+}
+
+fn load_store(c: &mut Criterion) {
+    let reference = &mut 1;
+    let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b101));
+    c.bench_function("load (acquire)", |b| b.iter(|| black_box(&ptr).load(Ordering::Acquire)));
+
+    let new = TagPtr::compose(reference, 0b010);
+    c.bench_function("store (release)", |b| {
+        b.iter(|| black_box(&ptr).store(black_box(new), Ordering::Release))
+    });
+}
+
+fn compare_exchange(c: &mut Criterion) {
+    let reference = &mut 1;
+    let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+    let order = (Ordering::AcqRel, Ordering::Acquire);
+
+    c.bench_function("compare_exchange_weak (uncontended)", |b| {
+        b.iter(|| {
+            let mut curr = ptr.load(Ordering::Relaxed);
+            while let Err(actual) =
+                ptr.compare_exchange_weak(curr, curr.update_tag(|tag| tag + 1), order)
+            {
+                curr = actual;
+            }
+        })
+    });
+
+    c.bench_function("compare_exchange_weak (contended, 2 threads)", |b| {
+        b.iter_custom(|iters| {
+            let done = AtomicBool::new(false);
+            thread::scope(|scope| {
+                scope.spawn(|| {
+                    while !done.load(Ordering::Relaxed) {
+                        ptr.fetch_xor(0b100, Ordering::Relaxed);
+                    }
+                });
+
+                let start = Instant::now();
+                for _ in 0..iters {
+                    let mut curr = ptr.load(Ordering::Relaxed);
+                    while let Err(actual) =
+                        ptr.compare_exchange_weak(curr, curr.update_tag(|tag| tag + 1), order)
+                    {
+                        curr = actual;
+                    }
+                }
+                let elapsed = start.elapsed();
+                done.store(true, Ordering::Relaxed);
+                elapsed
+            })
+        })
+    });
+}
+
+fn fetch_add(c: &mut Criterion) {
+    let reference = &mut 1;
+    let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+    c.bench_function("fetch_add + fetch_sub", |b| {
+        b.iter(|| {
+            ptr.fetch_add(1, Ordering::AcqRel);
+            ptr.fetch_sub(1, Ordering::AcqRel)
+        })
+    });
+}
+
+criterion_group!(benches, compose_decompose, load_store, compare_exchange, fetch_add);
+criterion_main!(benches);
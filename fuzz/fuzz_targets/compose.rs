@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+type TagPtr = tagptr::TagPtr<u64, 3>;
+
+fuzz_target!(|input: (TagPtr, usize)| {
+    let (ptr, tag) = input;
+    let (raw, _) = ptr.decompose();
+
+    let composed = TagPtr::compose(raw, tag);
+    assert_eq!(composed.decompose(), (raw, tag & TagPtr::TAG_MASK));
+});
@@ -0,0 +1,177 @@
+use core::{fmt, sync::atomic::Ordering};
+
+use crate::{AbaPtr, AtomicTagPtr, TagPtr};
+
+/********** impl inherent *************************************************************************/
+
+impl<T, const N: usize> AbaPtr<T, N> {
+    /// Creates a new `null` pointer with a version counter of zero.
+    #[inline]
+    pub const fn null() -> Self {
+        Self { inner: AtomicTagPtr::null() }
+    }
+
+    /// Creates a new pointer from the given (untagged) raw `ptr` with a
+    /// version counter of zero.
+    #[inline]
+    pub const fn new(ptr: *mut T) -> Self {
+        Self { inner: AtomicTagPtr::new(TagPtr::new(ptr)) }
+    }
+
+    /// Consumes the pointer and returns the contained raw pointer and version
+    /// counter.
+    #[inline]
+    pub fn into_inner(self) -> (*mut T, usize) {
+        self.inner.into_inner().decompose()
+    }
+
+    /// Loads the raw pointer and its current version counter.
+    ///
+    /// `load` takes an [`Ordering`] argument which describes the memory
+    /// ordering of this operation.
+    /// Possible values are [`SeqCst`][seq_cst], [`Acquire`][acq] and
+    /// [`Relaxed`][rlx].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`][rel] or [`AcqRel`][acq_rel].
+    ///
+    /// [rlx]: Ordering::Relaxed
+    /// [acq]: Ordering::Acquire
+    /// [rel]: Ordering::Release
+    /// [acq_rel]: Ordering::AcqRel
+    /// [seq_cst]: Ordering::SeqCst
+    #[inline]
+    pub fn load(&self, order: Ordering) -> (*mut T, usize) {
+        self.inner.load(order).decompose()
+    }
+
+    /// Stores `new_ptr` if the current value is `expected_ptr` with the
+    /// version counter `expected_aba`, incrementing the counter on success.
+    ///
+    /// The return value is a result indicating whether the new pointer was
+    /// written and containing the previous pointer and version counter.
+    /// Since the version counter is stored in the `N` tag bits, only the
+    /// lower `N` bits of `expected_aba` are compared, i.e., `expected_aba` is
+    /// truncated to `expected_aba & TAG_MASK`.
+    /// On success the returned value is guaranteed to be equal to
+    /// `(expected_ptr, expected_aba & TAG_MASK)`.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AbaPtr = tagptr::AbaPtr<i32, 2>;
+    ///
+    /// let (a, b) = (&mut 1 as *mut i32, &mut 2 as *mut i32);
+    /// let ptr = AbaPtr::new(a);
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    ///
+    /// assert_eq!(ptr.compare_exchange_ptr(a, 0, b, order), Ok((a, 0)));
+    /// assert_eq!(ptr.compare_exchange_ptr(b, 1, a, order), Ok((b, 1)));
+    /// // `a` is current again, but the stale version counter is detected
+    /// assert_eq!(ptr.compare_exchange_ptr(a, 0, b, order), Err((a, 2)));
+    /// ```
+    #[inline]
+    pub fn compare_exchange_ptr(
+        &self,
+        expected_ptr: *mut T,
+        expected_aba: usize,
+        new_ptr: *mut T,
+        order: (Ordering, Ordering),
+    ) -> Result<(*mut T, usize), (*mut T, usize)> {
+        let expected_aba = expected_aba & TagPtr::<T, N>::TAG_MASK;
+        let current = TagPtr::new(expected_ptr).set_tag(expected_aba);
+        let new = TagPtr::new(new_ptr).set_tag(expected_aba.wrapping_add(1));
+        self.inner
+            .compare_exchange(current, new, order)
+            .map(TagPtr::decompose)
+            .map_err(TagPtr::decompose)
+    }
+
+    /// Increments the version counter (wrapping around on overflow) without
+    /// changing the pointer and returns the previous counter value.
+    ///
+    /// This can be used to invalidate all previously loaded values without
+    /// exchanging the pointer.
+    /// See [`fetch_set_tag`][AtomicTagPtr::fetch_set_tag] for the possible
+    /// values of `order`.
+    #[inline]
+    pub fn fetch_increment_aba(&self, order: Ordering) -> usize {
+        self.inner.with_tag_update(order, |aba| aba.wrapping_add(1)).0.decompose_tag()
+    }
+}
+
+/********** impl Debug ****************************************************************************/
+
+impl<T, const N: usize> fmt::Debug for AbaPtr<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (ptr, aba) = self.load(Ordering::Relaxed);
+        f.debug_struct("AbaPtr").field("ptr", &ptr).field("aba", &aba).finish()
+    }
+}
+
+/********** impl Default **************************************************************************/
+
+impl<T, const N: usize> Default for AbaPtr<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{ptr, sync::atomic::Ordering};
+
+    type AbaPtr = crate::AbaPtr<i32, 2>;
+
+    #[test]
+    fn test_fetch_increment_aba() {
+        let reference = &mut 1;
+        let ptr = AbaPtr::new(reference);
+
+        assert_eq!(ptr.fetch_increment_aba(Ordering::Relaxed), 0);
+        assert_eq!(ptr.fetch_increment_aba(Ordering::Relaxed), 1);
+        assert_eq!(ptr.fetch_increment_aba(Ordering::Relaxed), 2);
+        // the counter wraps around after 2^N increments
+        assert_eq!(ptr.fetch_increment_aba(Ordering::Relaxed), 3);
+        assert_eq!(ptr.into_inner(), (reference as *mut _, 0));
+    }
+
+    #[test]
+    fn test_compare_exchange_ptr() {
+        let reference = &mut 1;
+        let ptr = AbaPtr::null();
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        assert_eq!(
+            ptr.compare_exchange_ptr(reference, 0, ptr::null_mut(), order),
+            Err((ptr::null_mut(), 0))
+        );
+        assert_eq!(
+            ptr.compare_exchange_ptr(ptr::null_mut(), 0, reference, order),
+            Ok((ptr::null_mut(), 0))
+        );
+        ptr.fetch_increment_aba(Ordering::Relaxed);
+        assert_eq!(
+            ptr.compare_exchange_ptr(reference, 1, reference, order),
+            Err((reference as *mut _, 2))
+        );
+        assert_eq!(ptr.load(Ordering::Relaxed), (reference as *mut _, 2));
+        // the expected version counter is truncated to the `N` tag bits
+        assert_eq!(
+            ptr.compare_exchange_ptr(reference, 0b110, reference, order),
+            Ok((reference as *mut _, 2))
+        );
+        assert_eq!(
+            std::format!("{:?}", ptr),
+            std::format!("AbaPtr {{ ptr: {:p}, aba: 3 }}", reference)
+        );
+    }
+}
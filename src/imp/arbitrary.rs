@@ -0,0 +1,48 @@
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{Null, TagNonNull, TagPtr};
+
+/********** impl Arbitrary ************************************************************************/
+
+impl<'a, T, const N: usize> Arbitrary<'a> for TagPtr<T, N> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_usize(u.arbitrary()?))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        usize::size_hint(depth)
+    }
+}
+
+impl<'a, T, const N: usize> Arbitrary<'a> for TagNonNull<T, N> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // retry as long as the generated pointer would be parsed as `null`
+        while !u.is_empty() {
+            if let Ok(ptr) = Self::new(u.arbitrary()?) {
+                return Ok(ptr);
+            }
+        }
+
+        Err(Error::NotEnoughData)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        (usize::size_hint(depth).0, None)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Null {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary().map(Null)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        usize::size_hint(depth)
+    }
+}
@@ -1,10 +1,17 @@
 use core::{
     fmt,
+    hash::{Hash, Hasher},
     marker::PhantomData,
-    sync::atomic::{AtomicUsize, Ordering},
+    ptr,
+    sync::atomic::Ordering,
 };
 
-use crate::{AtomicTagPtr, TagPtr};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::AtomicPtr;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::AtomicPtr;
+
+use crate::{AtomicTagPtr, SpinBackoff, TagNonNull, TagPtr};
 
 /********** impl Send + Sync **********************************************************************/
 
@@ -46,15 +53,50 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
         /// );
         /// ```
         pub const fn null() -> Self {
-            Self { inner: AtomicUsize::new(0), _marker: PhantomData }
+            Self { inner: AtomicPtr::new(ptr::null_mut()), _marker: PhantomData }
+        }
+    }
+
+    doc_comment! {
+        doc_null_with_tag!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use core::{ptr, sync::atomic::Ordering};
+        ///
+        /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+        ///
+        /// let ptr = AtomicTagPtr::null_with_tag(0b10);
+        /// assert_eq!(
+        ///     ptr.load(Ordering::Relaxed).decompose(),
+        ///     (ptr::null_mut(), 0b10)
+        /// );
+        /// ```
+        pub const fn null_with_tag(tag: usize) -> Self {
+            let inner = AtomicPtr::new(ptr::without_provenance_mut(tag & Self::TAG_MASK));
+            Self { inner, _marker: PhantomData }
         }
     }
 
     doc_comment! {
         doc_atomic_new!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use core::sync::atomic::Ordering;
+        ///
+        /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// static SENTINEL: AtomicTagPtr = AtomicTagPtr::new(TagPtr::dangling_with_tag(0b01));
+        ///
+        /// assert_eq!(SENTINEL.load(Ordering::Relaxed), TagPtr::dangling_with_tag(0b01));
+        /// ```
         #[inline]
-        pub fn new(marked_ptr: TagPtr<T, N>) -> Self {
-            Self { inner: AtomicUsize::new(marked_ptr.into_usize()), _marker: PhantomData }
+        pub const fn new(marked_ptr: TagPtr<T, N>) -> Self {
+            Self { inner: AtomicPtr::new(marked_ptr.into_raw()), _marker: PhantomData }
         }
     }
 
@@ -62,7 +104,7 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
         doc_atomic_into_inner!(),
         #[inline]
         pub fn into_inner(self) -> TagPtr<T, N> {
-            TagPtr::from_usize(self.inner.into_inner())
+            TagPtr::new(self.inner.into_inner())
         }
     }
 
@@ -72,8 +114,74 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     /// threads are concurrently accessing the atomic pointer.
     #[inline]
     pub fn get_mut(&mut self) -> &mut TagPtr<T, N> {
-        // SAFETY: the mutable self reference ensures the dereferencing is sound
-        unsafe { &mut *(self.inner.get_mut() as *mut usize as *mut _) }
+        // SAFETY: the mutable self reference ensures the dereferencing is sound and
+        // `TagPtr` has the same in-memory representation as `*mut T`
+        unsafe { &mut *(self.inner.get_mut() as *mut *mut T as *mut _) }
+    }
+
+    /// Returns a reference to the underlying atomic pointer.
+    ///
+    /// Any tag bits set in the stored value are visible through the returned
+    /// reference as part of the (raw) pointer.
+    ///
+    /// This is only available if the `portable-atomic` feature is disabled,
+    /// see `as_portable_atomic_ptr` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+    ///
+    /// let raw = ptr.as_atomic_ptr().load(Ordering::Relaxed);
+    /// assert_eq!(raw, TagPtr::compose(reference, 0b01).into_raw());
+    /// ```
+    #[cfg(not(feature = "portable-atomic"))]
+    #[inline]
+    pub fn as_atomic_ptr(&self) -> &core::sync::atomic::AtomicPtr<T> {
+        &self.inner
+    }
+
+    /// Converts a reference to an atomic pointer into a reference to an
+    /// atomic marked pointer.
+    ///
+    /// This is safe, since any pointer value is a valid marked pointer.
+    /// The lower `N` bits of the stored pointer are interpreted as tag bits.
+    ///
+    /// This is only available if the `portable-atomic` feature is disabled,
+    /// see `from_portable_atomic_ptr` otherwise.
+    #[cfg(not(feature = "portable-atomic"))]
+    #[inline]
+    pub fn from_atomic_ptr(ptr: &core::sync::atomic::AtomicPtr<T>) -> &Self {
+        // SAFETY: `AtomicTagPtr` is a transparent wrapper around `AtomicPtr`
+        unsafe { &*(ptr as *const AtomicPtr<T> as *const Self) }
+    }
+
+    /// Returns a reference to the underlying `portable_atomic::AtomicPtr`.
+    ///
+    /// Any tag bits set in the stored value are visible through the returned
+    /// reference as part of the (raw) pointer.
+    #[cfg(feature = "portable-atomic")]
+    #[inline]
+    pub fn as_portable_atomic_ptr(&self) -> &portable_atomic::AtomicPtr<T> {
+        &self.inner
+    }
+
+    /// Converts a reference to a `portable_atomic::AtomicPtr` into a reference
+    /// to an atomic marked pointer.
+    ///
+    /// This is safe, since any pointer value is a valid marked pointer.
+    /// The lower `N` bits of the stored pointer are interpreted as tag bits.
+    #[cfg(feature = "portable-atomic")]
+    #[inline]
+    pub fn from_portable_atomic_ptr(ptr: &portable_atomic::AtomicPtr<T>) -> &Self {
+        // SAFETY: `AtomicTagPtr` is a transparent wrapper around `AtomicPtr`
+        unsafe { &*(ptr as *const AtomicPtr<T> as *const Self) }
     }
 
     /// Loads the value of the atomic marked pointer.
@@ -94,7 +202,41 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     /// [seq_cst]: Ordering::SeqCst
     #[inline]
     pub fn load(&self, order: Ordering) -> TagPtr<T, N> {
-        TagPtr::from_usize(self.inner.load(order))
+        TagPtr::new(self.inner.load(order))
+    }
+
+    /// Loads the value of the atomic marked pointer and returns only its
+    /// separated tag value.
+    ///
+    /// `load_tag` takes an [`Ordering`] argument which describes the memory
+    /// ordering of this operation.
+    /// Possible values are [`SeqCst`][seq_cst], [`Acquire`][acq] and
+    /// [`Relaxed`][rlx].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`][rel] or [`AcqRel`][acq_rel].
+    ///
+    /// [rlx]: Ordering::Relaxed
+    /// [acq]: Ordering::Acquire
+    /// [rel]: Ordering::Release
+    /// [acq_rel]: Ordering::AcqRel
+    /// [seq_cst]: Ordering::SeqCst
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(&mut 1, 0b10));
+    /// assert_eq!(ptr.load_tag(Ordering::Relaxed), 0b10);
+    /// ```
+    #[inline]
+    pub fn load_tag(&self, order: Ordering) -> usize {
+        self.load(order).decompose_tag()
     }
 
     /// Stores a value into the atomic marked pointer.
@@ -115,7 +257,178 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     /// [seq_cst]: Ordering::SeqCst
     #[inline]
     pub fn store(&self, ptr: TagPtr<T, N>, order: Ordering) {
-        self.inner.store(ptr.into_usize(), order)
+        self.inner.store(ptr.into_raw(), order)
+    }
+
+    /// Performs a volatile (non-atomic) load of the marked pointer.
+    ///
+    /// This is intended for memory-mapped I/O, where the marked pointer is
+    /// located in a hardware register and must be accessed with plain volatile
+    /// reads rather than atomic instructions.
+    /// It provides no synchronization or ordering guarantees whatsoever.
+    ///
+    /// # Safety
+    ///
+    /// No other thread may concurrently write to the atomic marked pointer,
+    /// neither atomically nor through
+    /// [`volatile_store`][AtomicTagPtr::volatile_store], since this would
+    /// be a data race.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<u32, 2>;
+    /// type TagPtr = tagptr::TagPtr<u32, 2>;
+    ///
+    /// // stand-in for a memory-mapped register, which would usually be
+    /// // obtained from a fixed address, e.g., `&*(0x4000_0000 as *const _)`
+    /// let register = AtomicTagPtr::null();
+    /// let buffer = &mut 0u32;
+    ///
+    /// unsafe {
+    ///     register.volatile_store(TagPtr::compose(buffer, 0b01));
+    ///     assert_eq!(register.volatile_load(), TagPtr::compose(buffer, 0b01));
+    /// }
+    /// ```
+    #[cfg(feature = "mmio")]
+    #[inline]
+    pub unsafe fn volatile_load(&self) -> TagPtr<T, N> {
+        TagPtr::new(ptr::read_volatile(self.inner.as_ptr()))
+    }
+
+    /// Performs a volatile (non-atomic) store of the marked pointer.
+    ///
+    /// See [`volatile_load`][AtomicTagPtr::volatile_load] for the intended use
+    /// and an example.
+    ///
+    /// # Safety
+    ///
+    /// No other thread may concurrently access the atomic marked pointer,
+    /// neither atomically nor through volatile loads or stores, since this
+    /// would be a data race.
+    #[cfg(feature = "mmio")]
+    #[inline]
+    pub unsafe fn volatile_store(&self, ptr: TagPtr<T, N>) {
+        ptr::write_volatile(self.inner.as_ptr(), ptr.into_raw())
+    }
+
+    /// Stores `tag` into the atomic marked pointer, overwriting any previous
+    /// tag value but leaving the pointer bits unchanged.
+    ///
+    /// This is implemented as a compare-and-swap loop, which is retried as
+    /// long as other threads concurrently modify the atomic marked pointer.
+    /// See [`fetch_set_tag`][AtomicTagPtr::fetch_set_tag] for the possible
+    /// values of `order`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b10));
+    ///
+    /// ptr.store_tag(0b01, Ordering::Relaxed);
+    /// assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (reference as *mut _, 0b01));
+    /// ```
+    #[inline]
+    pub fn store_tag(&self, tag: usize, order: Ordering) {
+        let _ = self.fetch_set_tag(tag, order);
+    }
+
+    /// Stores `tag` into the atomic marked pointer, leaving the pointer bits
+    /// unchanged, and returns the previous tag value.
+    ///
+    /// This is implemented as a compare-and-swap loop, which is retried as
+    /// long as other threads concurrently modify the atomic marked pointer.
+    ///
+    /// `fetch_set_tag` takes an [`Ordering`] argument which describes the
+    /// memory ordering of this operation.
+    /// All ordering modes are possible.
+    /// Note that using [`Acquire`][acq] makes the store part of this operation
+    /// [`Relaxed`][rlx], and using [`Release`][rel] makes the load part
+    /// [`Relaxed`][rlx].
+    ///
+    /// [rlx]: Ordering::Relaxed
+    /// [acq]: Ordering::Acquire
+    /// [rel]: Ordering::Release
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b10));
+    ///
+    /// assert_eq!(ptr.fetch_set_tag(0b11, Ordering::Relaxed), 0b10);
+    /// assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (reference as *mut _, 0b11));
+    /// ```
+    #[inline]
+    pub fn fetch_set_tag(&self, tag: usize, order: Ordering) -> usize {
+        match self.fetch_update((order, Ordering::Relaxed), |ptr| Some(ptr.set_tag(tag))) {
+            Ok(prev) | Err(prev) => prev.decompose_tag(),
+        }
+    }
+
+    /// Replaces the tag of the atomic marked pointer with the result of `func`
+    /// applied to the current tag value, leaving the pointer bits unchanged.
+    ///
+    /// Returns the previous and the new marked pointer as a tuple.
+    ///
+    /// This is implemented as a compare-and-swap loop, which is retried until
+    /// it succeeds, so `func` may be called multiple times if the value has
+    /// been changed from other threads in the meantime.
+    /// Unlike [`fetch_update`][AtomicTagPtr::fetch_update], this operation can
+    /// not fail.
+    /// The pointer bits are never changed by this operation, but concurrent
+    /// changes to the tag bits are not detected (i.e., ABA on the tag bits is
+    /// possible).
+    ///
+    /// `with_tag_update` takes an [`Ordering`] argument which describes the
+    /// memory ordering of this operation.
+    /// All ordering modes are possible.
+    /// Note that using [`Acquire`][acq] makes the store part of this operation
+    /// [`Relaxed`][rlx], and using [`Release`][rel] makes the load part
+    /// [`Relaxed`][rlx].
+    ///
+    /// [rlx]: Ordering::Relaxed
+    /// [acq]: Ordering::Acquire
+    /// [rel]: Ordering::Release
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+    ///
+    /// let (prev, curr) = ptr.with_tag_update(Ordering::Relaxed, |tag| tag + 1);
+    /// assert_eq!(prev, TagPtr::compose(reference, 0b01));
+    /// assert_eq!(curr, TagPtr::compose(reference, 0b10));
+    /// ```
+    #[inline]
+    pub fn with_tag_update<F>(&self, order: Ordering, mut func: F) -> (TagPtr<T, N>, TagPtr<T, N>)
+    where
+        F: FnMut(usize) -> usize,
+    {
+        let mut new = TagPtr::null();
+        match self.fetch_update((order, Ordering::Relaxed), |ptr| {
+            new = ptr.update_tag(&mut func);
+            Some(new)
+        }) {
+            Ok(prev) | Err(prev) => (prev, new),
+        }
     }
 
     /// Stores a value into the atomic marked pointer and returns the previous
@@ -146,7 +459,69 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     /// assert!(prev.is_null());
     /// ```
     pub fn swap(&self, ptr: TagPtr<T, N>, order: Ordering) -> TagPtr<T, N> {
-        TagPtr::from_usize(self.inner.swap(ptr.into_usize(), order))
+        TagPtr::new(self.inner.swap(ptr.into_raw(), order))
+    }
+
+    /// Replaces the current value with `null` and returns the previous value.
+    ///
+    /// See [`swap`][AtomicTagPtr::swap] for the possible values of `order`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+    ///
+    /// assert_eq!(ptr.take(Ordering::Relaxed), TagPtr::compose(reference, 0b01));
+    /// assert!(ptr.load(Ordering::Relaxed).is_null());
+    /// ```
+    #[inline]
+    pub fn take(&self, order: Ordering) -> TagPtr<T, N> {
+        self.swap(TagPtr::null(), order)
+    }
+
+    /// Replaces the current value with a `null` pointer with the given
+    /// `null_tag` and returns the previous value.
+    ///
+    /// See [`swap`][AtomicTagPtr::swap] for the possible values of `order`.
+    #[inline]
+    pub fn take_with_tag(&self, null_tag: usize, order: Ordering) -> TagPtr<T, N> {
+        self.swap(TagPtr::null_with_tag(null_tag), order)
+    }
+
+    /// Stores the non-null `ptr` marked with `tag` and returns the previous
+    /// value, if it was non-null.
+    ///
+    /// See [`swap`][AtomicTagPtr::swap] for the possible values of `order`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{ptr::NonNull, sync::atomic::Ordering};
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagNonNull = tagptr::TagNonNull<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::null();
+    /// let non_null = TagNonNull::compose(NonNull::from(reference), 0);
+    ///
+    /// assert_eq!(ptr.put(non_null, 0b01, Ordering::Relaxed), None);
+    /// assert_eq!(ptr.put(non_null, 0b10, Ordering::Relaxed), Some(non_null.set_tag(0b01)));
+    /// ```
+    #[inline]
+    pub fn put(
+        &self,
+        ptr: TagNonNull<T, N>,
+        tag: usize,
+        order: Ordering,
+    ) -> Option<TagNonNull<T, N>> {
+        TagNonNull::new(self.swap(ptr.set_tag(tag).into_marked_ptr(), order)).ok()
     }
 
     /// Stores a value into the pointer if the current value is the same as
@@ -172,6 +547,27 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     /// [acq]: Ordering::Acquire
     /// [rel]: Ordering::Release
     /// [seq_cst]: Ordering::SeqCst
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+    ///
+    /// let curr = TagPtr::compose(reference, 0b01);
+    /// let new = TagPtr::compose(reference, 0b10);
+    /// let res = ptr.compare_exchange(curr, new, (Ordering::Relaxed, Ordering::Relaxed));
+    /// assert_eq!(res, Ok(curr));
+    ///
+    /// // the failed exchange returns the actually observed value
+    /// let res = ptr.compare_exchange(curr, new, (Ordering::Relaxed, Ordering::Relaxed));
+    /// assert_eq!(res, Err(new));
+    /// ```
     #[inline]
     pub fn compare_exchange(
         &self,
@@ -180,9 +576,46 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
         (success, failure): (Ordering, Ordering),
     ) -> Result<TagPtr<T, N>, TagPtr<T, N>> {
         self.inner
-            .compare_exchange(current.into_usize(), new.into_usize(), success, failure)
-            .map(|_| current)
-            .map_err(TagPtr::from_usize)
+            .compare_exchange(current.into_raw(), new.into_raw(), success, failure)
+            .map(TagPtr::new)
+            .map_err(TagPtr::new)
+    }
+
+    /// Stores a value into the pointer if the current value is the same as
+    /// `current`.
+    ///
+    /// Unlike [`compare_exchange`][AtomicTagPtr::compare_exchange], the
+    /// returned result contains the installed value (i.e., `new`) on success,
+    /// whereas on failure it contains the actually observed value.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+    ///
+    /// let curr = TagPtr::compose(reference, 0b01);
+    /// let new = TagPtr::compose(reference, 0b10);
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    /// assert_eq!(ptr.compare_exchange_new(curr, new, order), Ok(new));
+    /// assert_eq!(ptr.compare_exchange_new(curr, new, order), Err(new));
+    /// ```
+    #[inline]
+    pub fn compare_exchange_new(
+        &self,
+        current: TagPtr<T, N>,
+        new: TagPtr<T, N>,
+        order: (Ordering, Ordering),
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>> {
+        self.compare_exchange(current, new, order).map(|_| new)
     }
 
     /// Stores a value into the pointer if the current value is the same as
@@ -222,9 +655,525 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
         (success, failure): (Ordering, Ordering),
     ) -> Result<TagPtr<T, N>, TagPtr<T, N>> {
         self.inner
-            .compare_exchange_weak(current.into_usize(), new.into_usize(), success, failure)
-            .map(|_| current)
-            .map_err(TagPtr::from_usize)
+            .compare_exchange_weak(current.into_raw(), new.into_raw(), success, failure)
+            .map(TagPtr::new)
+            .map_err(TagPtr::new)
+    }
+
+    /// Replaces the tag of the atomic marked pointer with `new_tag` if its
+    /// current value is `ptr` marked with `expected_tag`.
+    ///
+    /// On success, `Ok(expected_tag)` is returned.
+    /// On failure, only the tag of the actually observed value is returned,
+    /// in which case either the tag or the pointer bits (or both) differed
+    /// from the expected value.
+    /// The pointer bits are never changed by this operation.
+    ///
+    /// This is equivalent to
+    /// [`compare_exchange_tag_only`][AtomicTagPtr::compare_exchange_tag_only].
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+    ///
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    /// assert_eq!(ptr.compare_and_update_tag(reference, 0b01, 0b10, order), Ok(0b01));
+    /// assert_eq!(ptr.compare_and_update_tag(reference, 0b01, 0b11, order), Err(0b10));
+    /// ```
+    #[inline]
+    pub fn compare_and_update_tag(
+        &self,
+        ptr: *mut T,
+        expected_tag: usize,
+        new_tag: usize,
+        order: (Ordering, Ordering),
+    ) -> Result<usize, usize> {
+        self.compare_exchange_tag_only(ptr, expected_tag, new_tag, order)
+    }
+
+    /// Replaces the tag of the atomic marked pointer with `new_tag` if its
+    /// current value is `ptr` marked with `expected_tag`.
+    ///
+    /// Unlike [`compare_and_update_tag`][AtomicTagPtr::compare_and_update_tag],
+    /// (or [`compare_exchange_tag_only`][AtomicTagPtr::compare_exchange_tag_only]),
+    /// this function is allowed to spuriously fail, even when the comparison
+    /// succeeds, which can result in more efficient code on some platforms.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    #[inline]
+    pub fn compare_and_update_tag_weak(
+        &self,
+        ptr: *mut T,
+        expected_tag: usize,
+        new_tag: usize,
+        order: (Ordering, Ordering),
+    ) -> Result<usize, usize> {
+        self.compare_exchange_tag_with(
+            ptr,
+            expected_tag,
+            new_tag,
+            order,
+            Self::compare_exchange_weak,
+        )
+    }
+
+    /// Stores `new` into the pointer if its current value is `null` with a
+    /// zero tag.
+    ///
+    /// Returns `Ok(())` if `new` was written and otherwise the observed value,
+    /// which is either non-null or a `null` pointer with a non-zero tag.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let (a, b) = (&mut 1, &mut 2);
+    /// let ptr = AtomicTagPtr::null();
+    /// let order = (Ordering::Release, Ordering::Acquire);
+    ///
+    /// assert_eq!(ptr.store_if_null(TagPtr::new(a), order), Ok(()));
+    /// assert_eq!(ptr.store_if_null(TagPtr::new(b), order), Err(TagPtr::new(a)));
+    /// ```
+    #[inline]
+    pub fn store_if_null(
+        &self,
+        new: TagPtr<T, N>,
+        order: (Ordering, Ordering),
+    ) -> Result<(), TagPtr<T, N>> {
+        self.compare_exchange(TagPtr::null(), new, order).map(|_| ())
+    }
+
+    /// Stores `new` into the pointer if its current value is `null` with the
+    /// given `expected_tag`, e.g., a tombstone marker.
+    ///
+    /// Returns `Ok(())` if `new` was written and otherwise the observed value.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// const TOMBSTONE: usize = 0b01;
+    ///
+    /// let new = TagPtr::new(&mut 1);
+    /// let ptr = AtomicTagPtr::null_with_tag(TOMBSTONE);
+    /// let order = (Ordering::Release, Ordering::Acquire);
+    ///
+    /// let res = ptr.store_if_null_tag_eq(0, new, order);
+    /// assert_eq!(res, Err(TagPtr::null_with_tag(TOMBSTONE)));
+    /// assert_eq!(ptr.store_if_null_tag_eq(TOMBSTONE, new, order), Ok(()));
+    /// ```
+    #[inline]
+    pub fn store_if_null_tag_eq(
+        &self,
+        expected_tag: usize,
+        new: TagPtr<T, N>,
+        order: (Ordering, Ordering),
+    ) -> Result<(), TagPtr<T, N>> {
+        self.compare_exchange(TagPtr::null_with_tag(expected_tag), new, order).map(|_| ())
+    }
+
+    /// Stores `new` into the pointer if its current value is `null`,
+    /// regardless of the `null` pointer's tag.
+    ///
+    /// Returns `Ok(tag)` with the tag of the replaced `null` pointer if `new`
+    /// was written and otherwise the observed non-null value.
+    /// Unlike [`store_if_null`][AtomicTagPtr::store_if_null], a change of the
+    /// tag alone does not cause this operation to fail, it is retried instead.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    /// The failure ordering is also used for the initial load.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let (a, b) = (&mut 1, &mut 2);
+    /// let ptr = AtomicTagPtr::null_with_tag(0b10);
+    /// let order = (Ordering::Release, Ordering::Acquire);
+    ///
+    /// assert_eq!(ptr.swap_if_null(TagPtr::new(a), order), Ok(0b10));
+    /// assert_eq!(ptr.swap_if_null(TagPtr::new(b), order), Err(TagPtr::new(a)));
+    /// ```
+    #[inline]
+    pub fn swap_if_null(
+        &self,
+        new: TagPtr<T, N>,
+        (success, failure): (Ordering, Ordering),
+    ) -> Result<usize, TagPtr<T, N>> {
+        let mut curr = self.load(failure);
+        while curr.is_null() {
+            match self.compare_exchange_weak(curr, new, (success, failure)) {
+                Ok(prev) => return Ok(prev.decompose_tag()),
+                Err(actual) => curr = actual,
+            }
+        }
+
+        Err(curr)
+    }
+
+    /// Stores `new` into the pointer if its current pointer bits are equal to
+    /// `expected_ptr`, regardless of the current tag value.
+    ///
+    /// The return value is a result indicating whether the new value was
+    /// written and containing the previous value.
+    /// On success this value is guaranteed to point to `expected_ptr`, but
+    /// may have any tag.
+    /// If only the tag is modified by another thread in the meantime, the
+    /// operation is retried, so it only fails if the pointer bits differ from
+    /// `expected_ptr`.
+    /// The operation takes effect atomically at the point of the successful
+    /// internal compare-exchange, i.e., `new` replaces exactly the returned
+    /// previous value.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    /// The failure ordering is also used for the initial load.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let (a, b) = (&mut 1, &mut 2);
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(a, 0b01));
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    ///
+    /// let res = ptr.compare_exchange_ptr(a, TagPtr::new(b), order);
+    /// assert_eq!(res, Ok(TagPtr::compose(a, 0b01)));
+    /// let res = ptr.compare_exchange_ptr(a, TagPtr::new(b), order);
+    /// assert_eq!(res, Err(TagPtr::new(b)));
+    /// ```
+    #[inline]
+    pub fn compare_exchange_ptr(
+        &self,
+        expected_ptr: *mut T,
+        new: TagPtr<T, N>,
+        (success, failure): (Ordering, Ordering),
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>> {
+        let mut curr = self.load(failure);
+        while curr.decompose_ptr() == expected_ptr {
+            match self.compare_exchange_weak(curr, new, (success, failure)) {
+                Ok(prev) => return Ok(prev),
+                Err(actual) => curr = actual,
+            }
+        }
+
+        Err(curr)
+    }
+
+    /// Stores `new_tag` into the pointer if its current value is the same as
+    /// `ptr` marked with `current_tag`, leaving the pointer bits unchanged.
+    ///
+    /// The return value is a result indicating whether the new tag was
+    /// written and containing the previous tag value.
+    /// On success this value is guaranteed to be equal to `current_tag`.
+    /// The operation fails without retrying if either the pointer or the tag
+    /// differ from the expected values.
+    ///
+    /// [`compare_and_update_tag`][AtomicTagPtr::compare_and_update_tag] is an
+    /// equivalent alias and
+    /// [`compare_and_update_tag_weak`][AtomicTagPtr::compare_and_update_tag_weak]
+    /// is the corresponding weak variant, which may fail spuriously.
+    ///
+    /// See [`compare_exchange`][AtomicTagPtr::compare_exchange] for the
+    /// possible values of the `(success, failure)` orderings.
+    ///
+    /// # Errors
+    ///
+    /// Fails if either the pointer or the tag differ from the expected values
+    /// and returns the observed tag value.
+    /// Since only the tag is returned, a failure caused solely by a different
+    /// pointer returns `Err(current_tag)`, which looks like a spurious failure
+    /// rather than a mismatch.
+    /// Callers retrying on failure must therefore reload the value and compare
+    /// its pointer as well, since retrying with the same arguments would loop
+    /// forever once the pointer has changed:
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let (a, b) = (&mut 1 as *mut i32, &mut 2 as *mut i32);
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(b, 0b01));
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    ///
+    /// // the pointer differs but the returned tag equals the expected one
+    /// assert_eq!(ptr.compare_exchange_tag_only(a, 0b01, 0b10, order), Err(0b01));
+    ///
+    /// // sets the tag of `a` to `0b10`, but only while `ptr` still points to `a`
+    /// let updated = loop {
+    ///     let (curr, tag) = ptr.load(Ordering::Relaxed).decompose();
+    ///     if curr != a {
+    ///         break false;
+    ///     }
+    ///
+    ///     if ptr.compare_exchange_tag_only(a, tag, 0b10, order).is_ok() {
+    ///         break true;
+    ///     }
+    /// };
+    ///
+    /// assert!(!updated);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    ///
+    /// assert_eq!(ptr.compare_exchange_tag_only(reference, 0b01, 0b10, order), Ok(0b01));
+    /// assert_eq!(ptr.compare_exchange_tag_only(reference, 0b01, 0b11, order), Err(0b10));
+    /// ```
+    #[inline]
+    pub fn compare_exchange_tag_only(
+        &self,
+        ptr: *mut T,
+        current_tag: usize,
+        new_tag: usize,
+        order: (Ordering, Ordering),
+    ) -> Result<usize, usize> {
+        self.compare_exchange_tag_with(ptr, current_tag, new_tag, order, Self::compare_exchange)
+    }
+
+    /// Fetches the value, and applies a function to it that returns an
+    /// optional new value.
+    ///
+    /// Returns a `Result` of `Ok(previous_value)` if the function returned
+    /// `Some(_)`, else `Err(previous_value)`.
+    ///
+    /// Note: This may call the function multiple times if the value has been
+    /// changed from other threads in the meantime, as long as the function
+    /// returns `Some(_)`, but the function will have been applied only once to
+    /// the stored value.
+    ///
+    /// `fetch_update` takes two [`Ordering`] arguments to describe the memory
+    /// ordering of this operation.
+    /// The first describes the required ordering for when the operation
+    /// finally succeeds while the second describes the required ordering for
+    /// loads.
+    /// These correspond to the success and failure orderings of
+    /// [`compare_exchange`][AtomicTagPtr::compare_exchange] respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+    ///
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    /// let res = ptr.fetch_update(order, |ptr| Some(ptr.set_tag(0b11)));
+    /// assert_eq!(res, Ok(TagPtr::new(reference)));
+    ///
+    /// let res = ptr.fetch_update(order, |_| None);
+    /// assert_eq!(res, Err(TagPtr::compose(reference, 0b11)));
+    /// ```
+    #[inline]
+    pub fn fetch_update<F>(
+        &self,
+        (set_order, fetch_order): (Ordering, Ordering),
+        mut func: F,
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>>
+    where
+        F: FnMut(TagPtr<T, N>) -> Option<TagPtr<T, N>>,
+    {
+        self.inner
+            .fetch_update(set_order, fetch_order, |curr| {
+                func(TagPtr::new(curr)).map(TagPtr::into_raw)
+            })
+            .map(TagPtr::new)
+            .map_err(TagPtr::new)
+    }
+
+    /// Fetches the value, and applies a function to it that returns an
+    /// optional new value, backing off with a [`SpinBackoff`] between retries.
+    ///
+    /// This behaves like [`fetch_update`][AtomicTagPtr::fetch_update], but is
+    /// better suited for highly contended pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+    ///
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    /// let res = ptr.update_backoff(order, |ptr| Some(ptr.set_tag(0b11)));
+    /// assert_eq!(res, Ok(TagPtr::new(reference)));
+    /// ```
+    #[inline]
+    pub fn update_backoff<F>(
+        &self,
+        (set_order, fetch_order): (Ordering, Ordering),
+        mut func: F,
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>>
+    where
+        F: FnMut(TagPtr<T, N>) -> Option<TagPtr<T, N>>,
+    {
+        let mut backoff = SpinBackoff::new();
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = func(prev) {
+            match self.compare_exchange_weak(prev, next, (set_order, fetch_order)) {
+                Ok(prev) => return Ok(prev),
+                Err(actual) => {
+                    prev = actual;
+                    backoff.snooze();
+                }
+            }
+        }
+
+        Err(prev)
+    }
+
+    /// Updates the value in-place by passing a mutable reference to the
+    /// current value to `func`, returning the new value.
+    ///
+    /// If `func` modifies the value, it is written back with a
+    /// compare-exchange, which is retried with the most recently observed
+    /// value if another thread has modified the pointer in the meantime.
+    /// Leaving the value unchanged aborts the update without writing and
+    /// returns the unchanged value.
+    /// Hence, `func` may be called multiple times.
+    ///
+    /// See [`fetch_update`][AtomicTagPtr::fetch_update] for the meaning of the
+    /// two [`Ordering`] arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    ///
+    /// let res = ptr.update(order, |ptr| *ptr = ptr.set_tag(0b01));
+    /// assert_eq!(res, TagPtr::compose(reference, 0b01));
+    /// ```
+    #[inline]
+    pub fn update<F>(
+        &self,
+        (set_order, fetch_order): (Ordering, Ordering),
+        mut func: F,
+    ) -> TagPtr<T, N>
+    where
+        F: FnMut(&mut TagPtr<T, N>),
+    {
+        let mut prev = self.load(fetch_order);
+        loop {
+            let mut next = prev;
+            func(&mut next);
+            if next == prev {
+                return next;
+            }
+
+            match self.compare_exchange_weak(prev, next, (set_order, fetch_order)) {
+                Ok(_) => return next,
+                Err(actual) => prev = actual,
+            }
+        }
+    }
+
+    /// Fetches the value and applies the fallible `func` to it, writing the
+    /// returned value on success and returning it.
+    ///
+    /// If `func` returns an error, the update is aborted without writing and
+    /// the error is returned.
+    /// Like [`update`][AtomicTagPtr::update], the compare-exchange is
+    /// retried if another thread has modified the pointer in the meantime, so
+    /// `func` may be called multiple times.
+    ///
+    /// See [`fetch_update`][AtomicTagPtr::fetch_update] for the meaning of the
+    /// two [`Ordering`] arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    ///
+    /// let set_tag = |ptr: TagPtr| if ptr.is_null() { Err("null") } else { Ok(ptr.set_tag(0b01)) };
+    ///
+    /// assert_eq!(ptr.try_update(order, set_tag), Ok(TagPtr::compose(reference, 0b01)));
+    /// ptr.store(TagPtr::null(), Ordering::Relaxed);
+    /// assert_eq!(ptr.try_update(order, set_tag), Err("null"));
+    /// ```
+    #[inline]
+    pub fn try_update<F, E>(
+        &self,
+        (set_order, fetch_order): (Ordering, Ordering),
+        mut func: F,
+    ) -> Result<TagPtr<T, N>, E>
+    where
+        F: FnMut(TagPtr<T, N>) -> Result<TagPtr<T, N>, E>,
+    {
+        let mut prev = self.load(fetch_order);
+        loop {
+            let next = func(prev)?;
+            match self.compare_exchange_weak(prev, next, (set_order, fetch_order)) {
+                Ok(_) => return Ok(next),
+                Err(actual) => prev = actual,
+            }
+        }
     }
 
     /// Adds `value` to the current tag value, returning the previous marked
@@ -269,8 +1218,56 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     /// ```
     #[inline]
     pub fn fetch_add(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
-        debug_assert!(value < Self::TAG_MASK, "`value` exceeds tag bits (would overflow)");
-        TagPtr::from_usize(self.inner.fetch_add(value, order))
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would overflow)");
+        let prev = TagPtr::new(self.inner.fetch_byte_add(value, order));
+        debug_assert!(
+            prev.decompose_tag() + value <= Self::TAG_MASK,
+            "tag overflowed into pointer bits"
+        );
+        prev
+    }
+
+    /// Adds `value` to the current tag value if the result fits into the tag
+    /// bits, returning the previous marked pointer.
+    ///
+    /// Returns `Ok(prev)` if the addition was performed and `Err(prev)` if it
+    /// would have overflowed into the pointer bits, in which case the stored
+    /// value is left unchanged.
+    /// Unlike [`fetch_add`][AtomicTagPtr::fetch_add], this operation is
+    /// implemented as a compare-exchange loop and never corrupts the pointer.
+    ///
+    /// See [`fetch_update`][AtomicTagPtr::fetch_update] for the meaning of the
+    /// two [`Ordering`] arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b10));
+    /// let order = (Ordering::Relaxed, Ordering::Relaxed);
+    ///
+    /// assert_eq!(ptr.saturating_fetch_add(1, order), Ok(TagPtr::compose(reference, 0b10)));
+    /// assert_eq!(ptr.saturating_fetch_add(1, order), Err(TagPtr::compose(reference, 0b11)));
+    /// ```
+    #[inline]
+    pub fn saturating_fetch_add(
+        &self,
+        value: usize,
+        order: (Ordering, Ordering),
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>> {
+        self.fetch_update(order, |ptr| {
+            let tag = ptr.decompose_tag().checked_add(value)?;
+            if tag <= Self::TAG_MASK {
+                Some(ptr.set_tag(tag))
+            } else {
+                None
+            }
+        })
     }
 
     /// Subtracts `value` from the current tag value, returning the previous
@@ -315,8 +1312,10 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     /// ```
     #[inline]
     pub fn fetch_sub(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
-        debug_assert!(value < Self::TAG_MASK, "`value` exceeds tag bits (would underflow)");
-        TagPtr::from_usize(self.inner.fetch_sub(value, order))
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would underflow)");
+        let prev = TagPtr::new(self.inner.fetch_byte_sub(value, order));
+        debug_assert!(prev.decompose_tag() >= value, "tag underflowed into pointer bits");
+        prev
     }
 
     /// Performs a bitwise "or" of `value` with the current tag value, returning
@@ -362,7 +1361,7 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     #[inline]
     pub fn fetch_or(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
         debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
-        TagPtr::from_usize(self.inner.fetch_or(Self::TAG_MASK & value, order))
+        TagPtr::new(self.inner.fetch_or(Self::TAG_MASK & value, order))
     }
 
     /// Performs a bitwise "and" of `value` with the current tag value,
@@ -409,7 +1408,120 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
     #[inline]
     pub fn fetch_and(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
         debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
-        TagPtr::from_usize(self.inner.fetch_and(Self::POINTER_MASK | value, order))
+        TagPtr::new(self.inner.fetch_and(Self::POINTER_MASK | value, order))
+    }
+
+    /// Performs a bitwise "xor" of `value` with the current tag value,
+    /// returning the previous marked pointer.
+    ///
+    /// Any bits of `value` outside of the tag bits are ignored, so the pointer
+    /// bits are never altered by this operation.
+    ///
+    /// `fetch_xor` takes takes an [`Ordering`] argument which describes the
+    /// memory ordering of this operation.
+    /// All ordering modes are possible.
+    /// Note that using [`Acquire`][acq] makes the store part of this operation
+    /// [`Relaxed`][rlx] and using [`Release`][rel] makes the load part
+    /// [`Relaxed`][rlx].
+    ///
+    /// [rlx]: Ordering::Relaxed
+    /// [acq]: Ordering::Acquire
+    /// [rel]: Ordering::Release
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b10));
+    ///
+    /// assert_eq!(
+    ///     ptr.fetch_xor(0b11, Ordering::Relaxed).decompose(),
+    ///     (reference as *mut _, 0b10)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ptr.load(Ordering::Relaxed).decompose(),
+    ///     (reference as *mut _, 0b01)
+    /// );
+    /// ```
+    #[inline]
+    pub fn fetch_xor(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
+        TagPtr::new(self.inner.fetch_xor(Self::TAG_MASK & value, order))
+    }
+
+    /// Performs a bitwise "nand" of `value` with the current tag value,
+    /// returning the previous marked pointer.
+    ///
+    /// Only the tag bits are negated, so the pointer bits are never altered by
+    /// this operation.
+    /// Since there is no native atomic "nand" operation on pointers, this is
+    /// implemented as a compare-and-swap loop (see
+    /// [`with_tag_update`][AtomicTagPtr::with_tag_update]).
+    ///
+    /// `fetch_nand` takes takes an [`Ordering`] argument which describes the
+    /// memory ordering of this operation.
+    /// All ordering modes are possible.
+    /// Note that using [`Acquire`][acq] makes the store part of this operation
+    /// [`Relaxed`][rlx] and using [`Release`][rel] makes the load part
+    /// [`Relaxed`][rlx].
+    ///
+    /// [rlx]: Ordering::Relaxed
+    /// [acq]: Ordering::Acquire
+    /// [rel]: Ordering::Release
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b10));
+    ///
+    /// assert_eq!(
+    ///     ptr.fetch_nand(0b11, Ordering::Relaxed).decompose(),
+    ///     (reference as *mut _, 0b10)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ptr.load(Ordering::Relaxed).decompose(),
+    ///     (reference as *mut _, 0b01)
+    /// );
+    /// ```
+    #[inline]
+    pub fn fetch_nand(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
+        self.with_tag_update(order, |tag| !(tag & value)).0
+    }
+
+    /// Composes the expected and new values from `ptr` and the given tags and
+    /// exchanges them using either the strong or the weak `cas`, returning
+    /// only the previous tag.
+    #[inline]
+    fn compare_exchange_tag_with(
+        &self,
+        ptr: *mut T,
+        current_tag: usize,
+        new_tag: usize,
+        order: (Ordering, Ordering),
+        cas: impl FnOnce(
+            &Self,
+            TagPtr<T, N>,
+            TagPtr<T, N>,
+            (Ordering, Ordering),
+        ) -> Result<TagPtr<T, N>, TagPtr<T, N>>,
+    ) -> Result<usize, usize> {
+        let current = TagPtr::compose(ptr, current_tag);
+        let new = TagPtr::compose(ptr, new_tag);
+        cas(self, current, new, order).map(TagPtr::decompose_tag).map_err(TagPtr::decompose_tag)
     }
 }
 
@@ -418,7 +1530,7 @@ impl<T, const N: usize> AtomicTagPtr<T, N> {
 impl<T, const N: usize> fmt::Debug for AtomicTagPtr<T, N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ptr, tag) = self.load(Ordering::SeqCst).decompose();
+        let (ptr, tag) = self.load(Ordering::Relaxed).decompose();
         f.debug_struct("AtomicTagPtr").field("ptr", &ptr).field("tag", &tag).finish()
     }
 }
@@ -447,11 +1559,606 @@ impl<T, const N: usize> From<TagPtr<T, N>> for AtomicTagPtr<T, N> {
     }
 }
 
+/********** impl From (TagNonNull<T, N>) **********************************************************/
+
+impl<T, const N: usize> From<TagNonNull<T, N>> for AtomicTagPtr<T, N> {
+    #[inline]
+    fn from(ptr: TagNonNull<T, N>) -> Self {
+        Self::new(ptr.into_marked_ptr())
+    }
+}
+
+/********** impl From (&T) ************************************************************************/
+
+impl<T, const N: usize> From<&T> for AtomicTagPtr<T, N> {
+    #[inline]
+    fn from(reference: &T) -> Self {
+        Self::new(TagPtr::from(reference))
+    }
+}
+
+/********** impl From (&mut T) ********************************************************************/
+
+impl<T, const N: usize> From<&mut T> for AtomicTagPtr<T, N> {
+    #[inline]
+    fn from(reference: &mut T) -> Self {
+        Self::new(TagPtr::from(reference))
+    }
+}
+
+/********** impl Binary ***************************************************************************/
+
+impl<T, const N: usize> fmt::Binary for AtomicTagPtr<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Binary::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+/********** impl LowerHex *************************************************************************/
+
+impl<T, const N: usize> fmt::LowerHex for AtomicTagPtr<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+/********** impl UpperHex *************************************************************************/
+
+impl<T, const N: usize> fmt::UpperHex for AtomicTagPtr<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
 /********** impl Pointer **************************************************************************/
 
 impl<T, const N: usize> fmt::Pointer for AtomicTagPtr<T, N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Pointer::fmt(&self.load(Ordering::SeqCst), f)
+        fmt::Pointer::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+/********** impl PartialEq ************************************************************************/
+
+/// Compares snapshots of both atomic marked pointers loaded with
+/// [`Relaxed`][Ordering::Relaxed] ordering.
+///
+/// The result may be outdated immediately, if either pointer is concurrently
+/// modified by another thread.
+impl<T, const N: usize> PartialEq for AtomicTagPtr<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.load(Ordering::Relaxed) == other.load(Ordering::Relaxed)
+    }
+}
+
+/********** impl Hash *****************************************************************************/
+
+/// Hashes a snapshot of the atomic marked pointer loaded with
+/// [`Relaxed`][Ordering::Relaxed] ordering.
+///
+/// The hash may change between observations, if the pointer is concurrently
+/// modified by another thread.
+impl<T, const N: usize> Hash for AtomicTagPtr<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.load(Ordering::Relaxed).hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::Ordering;
+
+    type AtomicTagPtr = crate::AtomicTagPtr<i32, 2>;
+    type TagPtr = crate::TagPtr<i32, 2>;
+
+    #[test]
+    fn test_compare_exchange_returns_loaded() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+
+        let curr = TagPtr::compose(reference, 0b01);
+        let new = TagPtr::compose(reference, 0b10);
+        let res = ptr.compare_exchange(curr, new, (Ordering::Relaxed, Ordering::Relaxed));
+        assert_eq!(res.map(TagPtr::into_usize), Ok(reference as *mut i32 as usize | 0b01));
+
+        let res = ptr.compare_exchange(curr, new, (Ordering::Relaxed, Ordering::Relaxed));
+        assert_eq!(res.map_err(TagPtr::into_usize), Err(reference as *mut i32 as usize | 0b10));
+    }
+
+    #[test]
+    fn test_compare_exchange_weak_returns_loaded() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b11));
+
+        let curr = TagPtr::compose(reference, 0b11);
+        let new = TagPtr::null();
+        loop {
+            match ptr.compare_exchange_weak(curr, new, (Ordering::Relaxed, Ordering::Relaxed)) {
+                Ok(prev) => {
+                    assert_eq!(prev.into_usize(), reference as *mut i32 as usize | 0b11);
+                    break;
+                }
+                Err(prev) => assert_eq!(prev, curr),
+            }
+        }
+
+        assert_eq!(ptr.load(Ordering::Relaxed), new);
+    }
+
+    #[test]
+    fn test_fetch_update() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        let mut calls = 0;
+        let res = ptr.fetch_update(order, |curr| {
+            calls += 1;
+            Some(curr.add_tag(1))
+        });
+        assert_eq!(res, Ok(TagPtr::new(reference)));
+        // the weak CAS may fail spuriously (e.g., under miri) and be retried
+        assert!(calls >= 1);
+
+        let res = ptr.fetch_update(order, |_| None);
+        assert_eq!(res, Err(TagPtr::compose(reference, 0b01)));
+        assert_eq!(ptr.load(Ordering::Relaxed), TagPtr::compose(reference, 0b01));
+    }
+
+    #[test]
+    fn test_tag_only() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+
+        assert_eq!(ptr.load_tag(Ordering::Relaxed), 0b01);
+        ptr.store_tag(0b10, Ordering::Relaxed);
+        assert_eq!(ptr.fetch_set_tag(0b111, Ordering::Relaxed), 0b10);
+        // excess bits are truncated and the pointer bits are left untouched
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (reference as *mut _, 0b11));
+    }
+
+    #[test]
+    fn test_null_with_tag() {
+        let ptr = AtomicTagPtr::null_with_tag(0b11);
+        assert!(ptr.load(Ordering::Relaxed).is_null());
+        assert_eq!(ptr.load_tag(Ordering::Relaxed), 0b11);
+    }
+
+    #[test]
+    fn test_fmt_addr() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b10));
+        let addr = reference as *mut i32 as usize | 0b10;
+
+        assert_eq!(std::format!("{:x}", ptr), std::format!("{:x}", addr));
+        assert_eq!(std::format!("{:#X}", ptr), std::format!("{:#X}", addr));
+        assert!(std::format!("{:b}", ptr).ends_with("10"));
+    }
+
+    #[test]
+    fn test_compare_exchange_tag_only() {
+        let (a, b) = (&mut 1, &mut 2);
+        let (a, b) = (a as *mut i32, b as *mut i32);
+        let ptr = AtomicTagPtr::new(TagPtr::compose(a, 0b01));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        assert_eq!(ptr.compare_exchange_tag_only(a, 0b01, 0b10, order), Ok(0b01));
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (a, 0b10));
+
+        // a (concurrent) change of the pointer must cause the operation to fail
+        ptr.store(TagPtr::compose(b, 0b10), Ordering::Relaxed);
+        assert_eq!(ptr.compare_exchange_tag_only(a, 0b10, 0b11, order), Err(0b10));
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (b, 0b10));
+    }
+
+    #[test]
+    fn test_compare_exchange_tag_only_ptr_mismatch() {
+        let (a, b) = (&mut 1, &mut 2);
+        let (a, b) = (a as *mut i32, b as *mut i32);
+        let ptr = AtomicTagPtr::new(TagPtr::compose(b, 0b01));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        // a mismatching pointer with the same tag fails with the *expected* tag, so
+        // retrying based on the error alone fails again
+        for _ in 0..2 {
+            assert_eq!(ptr.compare_exchange_tag_only(a, 0b01, 0b10, order), Err(0b01));
+            assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (b, 0b01));
+        }
+    }
+
+    #[test]
+    fn test_with_tag_update() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b11));
+
+        let mut calls = 0;
+        let (prev, curr) = ptr.with_tag_update(Ordering::Relaxed, |tag| {
+            calls += 1;
+            tag >> 1
+        });
+        // the weak CAS may fail spuriously (e.g., under miri) and be retried
+        assert!(calls >= 1);
+        assert_eq!(prev, TagPtr::compose(reference, 0b11));
+        assert_eq!(curr, TagPtr::compose(reference, 0b01));
+        assert_eq!(ptr.load(Ordering::Relaxed), curr);
+
+        // overflowing tags are truncated and never leak into the pointer bits
+        let (_, curr) = ptr.with_tag_update(Ordering::Relaxed, |_| usize::MAX);
+        assert_eq!(curr.decompose(), (reference as *mut _, 0b11));
+    }
+
+    #[test]
+    fn test_store_if_null_contended() {
+        use std::vec::Vec;
+
+        static VALUES: [i32; 4] = [0, 1, 2, 3];
+        let ptr = AtomicTagPtr::null();
+        let order = (Ordering::AcqRel, Ordering::Acquire);
+
+        let winners: Vec<usize> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..VALUES.len())
+                .map(|idx| {
+                    let ptr = &ptr;
+                    scope.spawn(move || {
+                        let new = TagPtr::new(&VALUES[idx] as *const i32 as *mut i32);
+                        ptr.store_if_null(new, order).ok().map(|_| idx)
+                    })
+                })
+                .collect();
+            handles.into_iter().filter_map(|handle| handle.join().unwrap()).collect()
+        });
+
+        assert_eq!(winners.len(), 1);
+        let winner = TagPtr::new(&VALUES[winners[0]] as *const i32 as *mut i32);
+        assert_eq!(ptr.load(Ordering::Relaxed), winner);
+        assert_eq!(ptr.store_if_null(TagPtr::null(), order), Err(winner));
+    }
+
+    #[test]
+    fn test_swap_if_null_contended() {
+        use std::vec::Vec;
+
+        static VALUES: [i32; 4] = [0, 1, 2, 3];
+        let ptr = AtomicTagPtr::null_with_tag(0b10);
+        let order = (Ordering::AcqRel, Ordering::Acquire);
+
+        let winners: Vec<(usize, usize)> = std::thread::scope(|scope| {
+            let tagger = scope.spawn(|| ptr.store_tag(0b01, Ordering::Relaxed));
+            let handles: Vec<_> = (0..VALUES.len())
+                .map(|idx| {
+                    let ptr = &ptr;
+                    scope.spawn(move || {
+                        let new = TagPtr::new(&VALUES[idx] as *const i32 as *mut i32);
+                        ptr.swap_if_null(new, order).ok().map(|tag| (idx, tag))
+                    })
+                })
+                .collect();
+            let winners = handles.into_iter().filter_map(|h| h.join().unwrap()).collect();
+            tagger.join().unwrap();
+            winners
+        });
+
+        assert_eq!(winners.len(), 1);
+        let (idx, tag) = winners[0];
+        assert!(tag == 0b10 || tag == 0b01);
+        let winner = TagPtr::new(&VALUES[idx] as *const i32 as *mut i32);
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose_ptr(), winner.decompose_ptr());
+        assert_eq!(
+            ptr.swap_if_null(TagPtr::null(), order).map_err(TagPtr::decompose_ptr),
+            Err(winner.decompose_ptr())
+        );
+    }
+
+    #[test]
+    fn test_debug_concurrent() {
+        let reference = &mut 1;
+        let raw: *mut i32 = reference;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(raw, 0b01));
+        let expected = std::format!("AtomicTagPtr {{ ptr: {:p}, tag: 1 }}", raw);
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| assert_eq!(std::format!("{:?}", ptr), expected));
+            }
+        });
+    }
+
+    #[test]
+    fn test_compare_exchange_new() {
+        let (a, b) = (&mut 1, &mut 2);
+        let ptr = AtomicTagPtr::new(TagPtr::compose(a, 0b01));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        let new = TagPtr::compose(b, 0b10);
+        assert_eq!(ptr.compare_exchange_new(TagPtr::compose(a, 0b01), new, order), Ok(new));
+        assert_eq!(ptr.compare_exchange_new(TagPtr::null(), TagPtr::new(a), order), Err(new));
+        assert_eq!(ptr.load(Ordering::Relaxed), new);
+    }
+
+    #[test]
+    fn test_take_put() {
+        let reference = &mut 1;
+        let non_null = crate::TagNonNull::<i32, 2>::compose(core::ptr::NonNull::from(reference), 0);
+        let ptr = AtomicTagPtr::null();
+
+        assert_eq!(ptr.put(non_null, 0b01, Ordering::Relaxed), None);
+        assert_eq!(ptr.take_with_tag(0b10, Ordering::Relaxed), non_null.set_tag(0b01).into());
+        assert_eq!(ptr.put(non_null, 0b11, Ordering::Relaxed), None);
+        assert_eq!(ptr.take(Ordering::Relaxed), non_null.set_tag(0b11).into());
+        assert_eq!(ptr.take(Ordering::Relaxed), TagPtr::null());
+    }
+
+    #[test]
+    fn test_from_references() {
+        let mut value = 1;
+        let expected = &mut value as *mut i32;
+
+        let ptr = AtomicTagPtr::from(&value);
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (expected, 0));
+
+        let ptr = AtomicTagPtr::from(&mut value);
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (expected, 0));
+
+        let non_null =
+            crate::TagNonNull::<i32, 2>::compose(core::ptr::NonNull::from(&mut value), 0b11);
+        let ptr = AtomicTagPtr::from(non_null);
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (expected, 0b11));
+    }
+
+    #[test]
+    fn test_update_backoff_contended() {
+        let ptr = crate::AtomicTagPtr::<u64, 3>::null();
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        std::thread::scope(|scope| {
+            for _ in 0..7 {
+                scope.spawn(|| {
+                    ptr.update_backoff(order, |ptr| Some(ptr.add_tag(1))).unwrap();
+                });
+            }
+        });
+
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose_tag(), 7);
+        assert_eq!(ptr.update_backoff(order, |_| None), Err(crate::TagPtr::null_with_tag(7)));
+    }
+
+    #[test]
+    fn test_fetch_ops_preserve_pointer() {
+        let reference = &mut 1;
+        let raw = reference as *mut i32;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b10));
+        let order = Ordering::Relaxed;
+
+        assert_eq!(ptr.fetch_add(1, order).decompose(), (raw, 0b10));
+        assert_eq!(ptr.fetch_sub(1, order).decompose(), (raw, 0b11));
+        assert_eq!(ptr.fetch_or(0b11, order).decompose(), (raw, 0b10));
+        assert_eq!(ptr.fetch_and(0b01, order).decompose(), (raw, 0b11));
+        assert_eq!(ptr.fetch_xor(0b11, order).decompose(), (raw, 0b01));
+        assert_eq!(ptr.fetch_nand(0b11, order).decompose(), (raw, 0b10));
+        assert_eq!(ptr.load(order).decompose(), (raw, 0b01));
+    }
+
+    #[test]
+    fn test_compare_and_update_tag() {
+        let (a, b) = (&mut 1, &mut 2);
+        let (a, b) = (a as *mut i32, b as *mut i32);
+        let ptr = AtomicTagPtr::new(TagPtr::compose(a, 0b01));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        assert_eq!(ptr.compare_and_update_tag(a, 0b01, 0b10, order), Ok(0b01));
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (a, 0b10));
+        // mismatching tag
+        assert_eq!(ptr.compare_and_update_tag(a, 0b01, 0b11, order), Err(0b10));
+        // mismatching pointer
+        assert_eq!(ptr.compare_and_update_tag(b, 0b10, 0b11, order), Err(0b10));
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (a, 0b10));
+
+        while ptr.compare_and_update_tag_weak(a, 0b10, 0b11, order).is_err() {}
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (a, 0b11));
+    }
+
+    #[test]
+    fn test_hash_eq() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash(ptr: &AtomicTagPtr) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            ptr.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let reference = &mut 1;
+        let a = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+        let b = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_eq!(hash(&a), {
+            let mut hasher = DefaultHasher::new();
+            a.load(Ordering::Relaxed).hash(&mut hasher);
+            hasher.finish()
+        });
+
+        b.store_tag(0b10, Ordering::Relaxed);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_saturating_fetch_add() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, AtomicTagPtr::TAG_MASK - 1));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        let max = TagPtr::compose(reference, AtomicTagPtr::TAG_MASK);
+        assert_eq!(ptr.saturating_fetch_add(0, order), Ok(max.set_tag(AtomicTagPtr::TAG_MASK - 1)));
+        assert_eq!(ptr.saturating_fetch_add(1, order), Ok(max.set_tag(AtomicTagPtr::TAG_MASK - 1)));
+        assert_eq!(ptr.saturating_fetch_add(1, order), Err(max));
+        assert_eq!(ptr.saturating_fetch_add(usize::MAX, order), Err(max));
+        assert_eq!(ptr.saturating_fetch_add(0, order), Ok(max));
+        assert_eq!(ptr.load(Ordering::Relaxed), max);
+    }
+
+    #[test]
+    fn test_saturating_fetch_add_tag_mask() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        let max = TagPtr::compose(reference, AtomicTagPtr::TAG_MASK);
+        assert_eq!(ptr.saturating_fetch_add(AtomicTagPtr::TAG_MASK, order), Ok(max.set_tag(0)));
+        assert_eq!(ptr.saturating_fetch_add(AtomicTagPtr::TAG_MASK, order), Err(max));
+        assert_eq!(ptr.load(Ordering::Relaxed), max);
+    }
+
+    #[test]
+    fn test_fetch_add_sub_tag_mask() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::new(reference));
+
+        // adding and subtracting the full tag mask to and from a zero tag is legal
+        let prev = ptr.fetch_add(AtomicTagPtr::TAG_MASK, Ordering::Relaxed);
+        assert_eq!(prev, TagPtr::new(reference));
+        let max = TagPtr::compose(reference, AtomicTagPtr::TAG_MASK);
+        assert_eq!(ptr.load(Ordering::Relaxed), max);
+
+        assert_eq!(ptr.fetch_sub(AtomicTagPtr::TAG_MASK, Ordering::Relaxed), max);
+        assert_eq!(ptr.load(Ordering::Relaxed), TagPtr::new(reference));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "tag overflowed into pointer bits")]
+    fn test_fetch_add_overflow() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, AtomicTagPtr::TAG_MASK));
+        ptr.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "tag underflowed into pointer bits")]
+    fn test_fetch_sub_underflow() {
+        let reference = &mut 1;
+        let ptr = AtomicTagPtr::new(TagPtr::compose(reference, 0b01));
+        ptr.fetch_sub(0b10, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn test_update() {
+        let (a, b) = (&mut 1, &mut 2);
+        let ptr = AtomicTagPtr::new(TagPtr::new(a));
+        let order = (Ordering::AcqRel, Ordering::Acquire);
+
+        assert_eq!(ptr.update(order, |ptr| *ptr = ptr.set_tag(0b10)), TagPtr::compose(a, 0b10));
+        // leaving the value unchanged aborts the update
+        assert_eq!(ptr.update(order, |_| {}), TagPtr::compose(a, 0b10));
+        assert_eq!(ptr.load(Ordering::Relaxed), TagPtr::compose(a, 0b10));
+
+        let res = ptr.try_update(order, |curr| {
+            if curr.decompose_tag() == 0b10 {
+                Ok(TagPtr::new(b))
+            } else {
+                Err(curr)
+            }
+        });
+        assert_eq!(res, Ok(TagPtr::new(b)));
+        let res =
+            ptr.try_update(
+                order,
+                |curr| if curr.decompose_tag() == 0b10 { Ok(curr) } else { Err(curr) },
+            );
+        assert_eq!(res, Err(TagPtr::new(b)));
+        assert_eq!(ptr.load(Ordering::Relaxed), TagPtr::new(b));
+    }
+
+    #[test]
+    fn test_update_contended() {
+        const THREADS: usize = 4;
+        const ITERS: usize = 1000;
+
+        let ptr = AtomicTagPtr::null();
+        let order = (Ordering::AcqRel, Ordering::Acquire);
+        std::thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..ITERS {
+                        ptr.update(order, |ptr| *ptr = ptr.wrapping_add(1));
+                    }
+                });
+            }
+        });
+
+        let expected = TagPtr::null().wrapping_add(THREADS * ITERS);
+        assert_eq!(ptr.load(Ordering::Relaxed), expected);
+    }
+
+    #[test]
+    fn test_compare_exchange_ptr_contended() {
+        use core::sync::atomic::AtomicBool;
+
+        let (a, b) = (&mut 1, &mut 2);
+        let (a, b): (*mut i32, *mut i32) = (a, b);
+        let ptr = AtomicTagPtr::new(TagPtr::new(a));
+        let order = (Ordering::AcqRel, Ordering::Acquire);
+        let done = AtomicBool::new(false);
+
+        let res = std::thread::scope(|scope| {
+            // concurrently modifies only the tag, which must not cause failure
+            scope.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    ptr.fetch_xor(0b11, Ordering::Relaxed);
+                }
+            });
+
+            let res = ptr.compare_exchange_ptr(a, TagPtr::new(b), order);
+            done.store(true, Ordering::Relaxed);
+            res
+        });
+
+        assert_eq!(res.unwrap().decompose_ptr(), a);
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose_ptr(), b);
+        assert_eq!(
+            ptr.compare_exchange_ptr(a, TagPtr::null(), order).unwrap_err().decompose_ptr(),
+            b
+        );
+    }
+
+    #[cfg(not(feature = "portable-atomic"))]
+    #[test]
+    fn test_atomic_ptr_conversion() {
+        use core::sync::atomic::AtomicPtr;
+
+        let reference = &mut 1;
+        let raw: *mut i32 = reference;
+        let atomic = AtomicPtr::new(raw.wrapping_byte_add(0b10));
+
+        let ptr = AtomicTagPtr::from_atomic_ptr(&atomic);
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (raw, 0b10));
+        ptr.store_tag(0b01, Ordering::Relaxed);
+        assert_eq!(atomic.load(Ordering::Relaxed), raw.wrapping_byte_add(0b01));
+        assert!(core::ptr::eq(ptr.as_atomic_ptr(), &atomic));
+    }
+
+    #[cfg(feature = "portable-atomic")]
+    #[test]
+    fn test_portable_atomic_ptr_conversion() {
+        use portable_atomic::AtomicPtr;
+
+        let reference = &mut 1;
+        let raw: *mut i32 = reference;
+        let atomic = AtomicPtr::new(raw.wrapping_byte_add(0b10));
+
+        let ptr = AtomicTagPtr::from_portable_atomic_ptr(&atomic);
+        assert_eq!(ptr.load(Ordering::Relaxed).decompose(), (raw, 0b10));
+        ptr.store_tag(0b01, Ordering::Relaxed);
+        assert_eq!(atomic.load(Ordering::Relaxed), raw.wrapping_byte_add(0b01));
+        assert!(core::ptr::eq(ptr.as_portable_atomic_ptr(), &atomic));
     }
 }
@@ -0,0 +1,77 @@
+use core::hint;
+
+use crate::SpinBackoff;
+
+/********** impl inherent *************************************************************************/
+
+impl SpinBackoff {
+    /// The default maximum number of spins per call to
+    /// [`snooze`][SpinBackoff::snooze].
+    pub const DEFAULT_MAX_SPIN: u32 = 64;
+
+    /// Creates a new backoff with the [default][SpinBackoff::DEFAULT_MAX_SPIN]
+    /// maximum number of spins.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_max_spin(Self::DEFAULT_MAX_SPIN)
+    }
+
+    /// Creates a new backoff, which spins at most `max_spin` times per call to
+    /// [`snooze`][SpinBackoff::snooze].
+    #[inline]
+    pub const fn with_max_spin(max_spin: u32) -> Self {
+        Self { spins: 1, max_spin }
+    }
+
+    /// Signals the processor once that it is inside a busy-wait spin-loop.
+    #[inline]
+    pub fn spin(&mut self) {
+        hint::spin_loop();
+    }
+
+    /// Signals the processor that it is inside a busy-wait spin-loop for a
+    /// number of times, which doubles with each call until the maximum number
+    /// of spins is reached.
+    #[inline]
+    pub fn snooze(&mut self) {
+        for _ in 0..self.spins.min(self.max_spin) {
+            hint::spin_loop();
+        }
+
+        self.spins = self.spins.saturating_mul(2).min(self.max_spin.max(1));
+    }
+
+    /// Resets the backoff to its initial state.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.spins = 1;
+    }
+}
+
+/********** impl Default **************************************************************************/
+
+impl Default for SpinBackoff {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpinBackoff;
+
+    #[test]
+    fn test_snooze() {
+        let mut backoff = SpinBackoff::with_max_spin(4);
+        let spins: [u32; 4] = core::array::from_fn(|_| {
+            let spins = backoff.spins;
+            backoff.snooze();
+            spins
+        });
+
+        assert_eq!(spins, [1, 2, 4, 4]);
+        backoff.reset();
+        assert_eq!(backoff.spins, 1);
+    }
+}
@@ -0,0 +1,179 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+use alloc::boxed::Box;
+
+use crate::{TagNonNull, TaggedBox};
+
+/********** impl Send + Sync **********************************************************************/
+
+unsafe impl<T: Send, const N: usize> Send for TaggedBox<T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for TaggedBox<T, N> {}
+
+/********** impl inherent *************************************************************************/
+
+impl<T, const N: usize> TaggedBox<T, N> {
+    doc_comment! {
+        doc_tag_bits!(),
+        pub const TAG_BITS: usize = N;
+    }
+
+    doc_comment! {
+        doc_tag_mask!(),
+        pub const TAG_MASK: usize = crate::mark_mask(Self::TAG_BITS);
+    }
+
+    /// Allocates `val` on the heap and creates a new tagged box from it and a
+    /// `tag` value.
+    ///
+    /// Fails to compile if the alignment of `T` is too small for `N` tag
+    /// bits, since the tag would otherwise be stored in the address bits of
+    /// the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```compile_fail
+    /// // `u8` has an alignment of 1 and therefore no unused bits
+    /// let _ = tagptr::TaggedBox::<u8, 2>::new(1, 0b01);
+    /// ```
+    #[inline]
+    pub fn new(val: T, tag: usize) -> Self {
+        crate::assert_alignment_const::<T, N>();
+        Self { inner: TagNonNull::from_box(Box::new(val), tag), _marker: PhantomData }
+    }
+
+    /// Creates a tagged box from a marked non-null pointer, taking ownership
+    /// of the pointed-to value.
+    ///
+    /// # Safety
+    ///
+    /// The same safety caveats as with [`Box::from_raw`] apply, i.e., the
+    /// pointer must have been allocated by a `Box` using the global allocator
+    /// (e.g., by [`into_raw`][TaggedBox::into_raw]) and must not be used
+    /// again afterwards.
+    #[inline]
+    pub unsafe fn from_raw(ptr: TagNonNull<T, N>) -> Self {
+        Self { inner: ptr, _marker: PhantomData }
+    }
+
+    /// Consumes the tagged box and returns the marked non-null pointer without
+    /// dropping the owned value.
+    ///
+    /// The caller becomes responsible for the memory, e.g., by converting the
+    /// pointer back with [`from_raw`][TaggedBox::from_raw].
+    #[inline]
+    pub fn into_raw(self) -> TagNonNull<T, N> {
+        let inner = self.inner;
+        mem::forget(self);
+        inner
+    }
+
+    doc_comment! {
+        doc_decompose_tag!(),
+        #[inline]
+        pub fn tag(&self) -> usize {
+            self.inner.decompose_tag()
+        }
+    }
+
+    doc_comment! {
+        doc_set_tag!(),
+        #[inline]
+        pub fn set_tag(self, tag: usize) -> Self {
+            Self { inner: self.into_raw().set_tag(tag), _marker: PhantomData }
+        }
+    }
+}
+
+/********** impl Debug ****************************************************************************/
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for TaggedBox<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TaggedBox").field("val", &**self).field("tag", &self.tag()).finish()
+    }
+}
+
+/********** impl Deref ****************************************************************************/
+
+impl<T, const N: usize> Deref for TaggedBox<T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the pointer is owned and was allocated by a `Box`
+        unsafe { &*self.inner.decompose_ptr() }
+    }
+}
+
+/********** impl DerefMut *************************************************************************/
+
+impl<T, const N: usize> DerefMut for TaggedBox<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the pointer is owned and was allocated by a `Box`
+        unsafe { &mut *self.inner.decompose_ptr() }
+    }
+}
+
+/********** impl Drop *****************************************************************************/
+
+impl<T, const N: usize> Drop for TaggedBox<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: the pointer is owned and was allocated by a `Box`
+        unsafe { drop(self.inner.into_box()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    type TaggedBox<T> = crate::TaggedBox<T, 2>;
+
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_drop() {
+        let drops = Cell::new(0);
+        let boxed = TaggedBox::new(DropCounter(&drops), 0b01);
+        let boxed = boxed.set_tag(0b10);
+        assert_eq!(boxed.tag(), 0b10);
+        assert_eq!(drops.get(), 0);
+
+        drop(boxed);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_raw_round_trip() {
+        let drops = Cell::new(0);
+        let ptr = TaggedBox::new(DropCounter(&drops), 0b11).into_raw();
+        assert_eq!(drops.get(), 0);
+        assert_eq!(ptr.decompose_tag(), 0b11);
+
+        let boxed = unsafe { TaggedBox::from_raw(ptr) };
+        assert_eq!(boxed.tag(), 0b11);
+        drop(boxed);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_deref() {
+        let mut boxed = TaggedBox::new(1, 0b01);
+        *boxed += 1;
+        assert_eq!(*boxed, 2);
+        assert_eq!(std::format!("{:?}", boxed), "TaggedBox { val: 2, tag: 1 }");
+    }
+}
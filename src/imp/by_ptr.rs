@@ -0,0 +1,80 @@
+use core::hash::{Hash, Hasher};
+
+use crate::{ByPtr, TagNonNull, TagPtr};
+
+/********** impl PartialEq ************************************************************************/
+
+impl<T, const N: usize> PartialEq for ByPtr<TagPtr<T, N>> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.decompose_ptr() == other.0.decompose_ptr()
+    }
+}
+
+impl<T, const N: usize> PartialEq for ByPtr<TagNonNull<T, N>> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.decompose_non_null() == other.0.decompose_non_null()
+    }
+}
+
+/********** impl Eq *******************************************************************************/
+
+impl<T, const N: usize> Eq for ByPtr<TagPtr<T, N>> {}
+
+impl<T, const N: usize> Eq for ByPtr<TagNonNull<T, N>> {}
+
+/********** impl Hash *****************************************************************************/
+
+impl<T, const N: usize> Hash for ByPtr<TagPtr<T, N>> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.decompose_ptr().hash(state)
+    }
+}
+
+impl<T, const N: usize> Hash for ByPtr<TagNonNull<T, N>> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.decompose_non_null().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ptr::NonNull;
+    use std::collections::HashSet;
+
+    use crate::ByPtr;
+
+    type TagNonNull = crate::TagNonNull<i32, 2>;
+    type TagPtr = crate::TagPtr<i32, 2>;
+
+    #[test]
+    fn test_by_ptr_ignores_tag() {
+        let (a, b) = (&mut 1, &mut 2);
+        let (a, b): (*mut i32, *mut i32) = (a, b);
+
+        let set: HashSet<_> = [TagPtr::compose(a, 0b01), TagPtr::compose(a, 0b10), TagPtr::new(b)]
+            .iter()
+            .copied()
+            .map(ByPtr)
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&ByPtr(TagPtr::compose(a, 0b11))));
+        assert!(!set.contains(&ByPtr(TagPtr::null_with_tag(0b01))));
+        assert_eq!(ByPtr(TagPtr::null_with_tag(0b01)), ByPtr(TagPtr::null()));
+    }
+
+    #[test]
+    fn test_by_ptr_non_null() {
+        let reference = &mut 1;
+        let a = TagNonNull::compose(NonNull::from(&mut *reference), 0b01);
+        let b = a.set_tag(0b10);
+
+        assert_ne!(a, b);
+        assert_eq!(ByPtr(a), ByPtr(b));
+        let set: HashSet<_> = [ByPtr(a), ByPtr(b)].iter().copied().collect();
+        assert_eq!(set.len(), 1);
+    }
+}
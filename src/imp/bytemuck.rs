@@ -0,0 +1,22 @@
+use bytemuck::{Pod, Zeroable, ZeroableInOption};
+
+use crate::{AtomicTagPtr, TagNonNull, TagPtr};
+
+/********** impl Zeroable *************************************************************************/
+
+// SAFETY: the all-zeros bit pattern is a `null` pointer with a zero tag
+unsafe impl<T, const N: usize> Zeroable for TagPtr<T, N> {}
+// SAFETY: the all-zeros bit pattern is a `null` pointer with a zero tag
+unsafe impl<T, const N: usize> Zeroable for AtomicTagPtr<T, N> {}
+
+/********** impl ZeroableInOption *****************************************************************/
+
+// SAFETY: `TagNonNull` is a transparent `NonNull`, so `None` is represented as
+// all zeros
+unsafe impl<T, const N: usize> ZeroableInOption for TagNonNull<T, N> {}
+
+/********** impl Pod ******************************************************************************/
+
+// SAFETY: `TagPtr` is a transparent `*mut T` and any bit pattern valid for the
+// latter is a valid (if not necessarily de-referencable) marked pointer
+unsafe impl<T: 'static, const N: usize> Pod for TagPtr<T, N> where *mut T: Pod {}
@@ -4,10 +4,15 @@ use core::{
     fmt,
     hash::{Hash, Hasher},
     marker::PhantomData,
-    mem,
-    ptr::NonNull,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign},
+    ptr::{self, NonNull},
 };
 
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
 use crate::{Null, TagNonNull, TagPtr};
 
 /********** impl Clone ****************************************************************************/
@@ -38,6 +43,26 @@ impl<T, const N: usize> TagNonNull<T, N> {
         pub const POINTER_MASK: usize = !Self::TAG_MASK;
     }
 
+    /// Returns the number of available tag bits, i.e.,
+    /// [`TAG_BITS`][Self::TAG_BITS].
+    #[inline]
+    pub const fn tag_bits_count(self) -> usize {
+        Self::TAG_BITS
+    }
+
+    /// Returns the bit mask for the tag bits, i.e., [`TAG_MASK`][Self::TAG_MASK].
+    #[inline]
+    pub const fn tag_mask(self) -> usize {
+        Self::TAG_MASK
+    }
+
+    /// Returns the bit mask for the pointer bits, i.e.,
+    /// [`POINTER_MASK`][Self::POINTER_MASK].
+    #[inline]
+    pub const fn pointer_mask(self) -> usize {
+        Self::POINTER_MASK
+    }
+
     const COMPOSE_ERR_MSG: &'static str =
         "argument `ptr` is mis-aligned for `N` tag bits and could be parsed as marked `null` \
         pointer.";
@@ -56,6 +81,11 @@ impl<T, const N: usize> TagNonNull<T, N> {
 
     doc_comment! {
         doc_from_usize!(),
+        ///
+        /// # Safety
+        ///
+        /// The caller has to ensure that `val` represents neither a marked nor
+        /// an unmarked `null` pointer.
         #[inline]
         pub const unsafe fn from_usize(val: usize) -> Self {
             Self { inner: NonNull::new_unchecked(val as *mut _), _marker: PhantomData }
@@ -77,6 +107,36 @@ impl<T, const N: usize> TagNonNull<T, N> {
         }
     }
 
+    doc_comment! {
+        doc_widen_tag!(),
+        #[inline]
+        pub fn widen_tag<const M: usize>(self) -> TagNonNull<T, M> {
+            const { assert!(M >= N, "`M` must not be less than `N`") };
+            debug_assert_eq!(
+                self.decompose_ptr().addr() & crate::mark_mask(M),
+                0,
+                "pointer is mis-aligned for `M` tag bits"
+            );
+            TagNonNull { inner: self.inner, _marker: PhantomData }
+        }
+    }
+
+    doc_comment! {
+        doc_try_narrow_tag!(),
+        ///
+        /// # Errors
+        ///
+        /// Fails, if the current tag value does not fit into `M` bits.
+        #[inline]
+        pub fn try_narrow_tag<const M: usize>(self) -> Option<TagNonNull<T, M>> {
+            const { assert!(M <= N, "`M` must not be greater than `N`") };
+            match self.decompose_tag() & !crate::mark_mask(M) {
+                0 => Some(TagNonNull { inner: self.inner, _marker: PhantomData }),
+                _ => None,
+            }
+        }
+    }
+
     doc_comment! {
         doc_into_usize!(),
         #[inline]
@@ -85,6 +145,49 @@ impl<T, const N: usize> TagNonNull<T, N> {
         }
     }
 
+    doc_comment! {
+        doc_addr!(),
+        #[inline]
+        pub fn addr(self) -> usize {
+            self.inner.as_ptr().addr()
+        }
+    }
+
+    /// Creates a new marked non-null pointer with the given address,
+    /// including any tag bits, and the provenance of `self`.
+    ///
+    /// See [`pointer::with_addr`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the pointer bits of `addr` are zero, in which case a [`Null`]
+    /// instance is returned containing the tag value of `addr`.
+    #[inline]
+    pub fn with_addr(self, addr: usize) -> Result<Self, Null> {
+        Self::try_from_marked(self.inner.as_ptr().with_addr(addr))
+    }
+
+    doc_comment! {
+        doc_expose_addr!(),
+        #[inline]
+        pub fn expose_addr(self) -> usize {
+            self.inner.as_ptr().expose_provenance()
+        }
+    }
+
+    doc_comment! {
+        doc_from_exposed_addr!(),
+        ///
+        /// # Errors
+        ///
+        /// Fails if the pointer bits of `addr` are zero, in which case a
+        /// [`Null`] instance is returned containing the tag value of `addr`.
+        #[inline]
+        pub fn from_exposed_addr(addr: usize) -> Result<Self, Null> {
+            Self::try_from_marked(ptr::with_exposed_provenance_mut(addr))
+        }
+    }
+
     /// Converts `self` into a (nullable) marked pointer.
     #[inline]
     pub const fn into_marked_ptr(self) -> TagPtr<T, N> {
@@ -105,10 +208,8 @@ impl<T, const N: usize> TagNonNull<T, N> {
     /// Creates a new pointer that is dangling but well aligned.
     #[inline]
     pub const fn dangling() -> Self {
-        let alignment = mem::align_of::<T>();
-        let val = if alignment >= Self::TAG_MASK + 1 { alignment } else { Self::TAG_MASK + 1 };
-        // SAFETY: a type's alignment is never 0, so val is always non-zero
-        unsafe { Self::from_usize(val) }
+        // SAFETY: a type's alignment is never 0, so the address is always non-zero
+        unsafe { Self::from_usize(crate::dangling_addr::<T>(N)) }
     }
 
     doc_comment! {
@@ -124,6 +225,59 @@ impl<T, const N: usize> TagNonNull<T, N> {
         }
     }
 
+    /// Composes a new marked pointer from a raw (non-null) `ptr` and a `tag`
+    /// value like [`compose`][Self::compose], but fails to compile if the
+    /// alignment of `T` is too small for `N` tag bits.
+    ///
+    /// Unlike `compose`, this can not be used with type-erased pointers such
+    /// as `TagNonNull<(), N>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`compose`][Self::compose].
+    ///
+    /// # Examples
+    ///
+    /// ```compile_fail
+    /// use core::ptr::NonNull;
+    ///
+    /// // `u16` has an alignment of 2 and therefore only 1 unused bit
+    /// let ptr = NonNull::from(&mut 1u16);
+    /// let _ = tagptr::TagNonNull::<u16, 3>::compose_checked(ptr, 0b101);
+    /// ```
+    #[inline]
+    pub fn compose_checked(ptr: NonNull<T>, tag: usize) -> Self {
+        crate::assert_alignment_const::<T, N>();
+        Self::compose(ptr, tag)
+    }
+
+    doc_comment! {
+        doc_compose_exact!(),
+        /// # Panics
+        ///
+        /// Panics under the same conditions as [`compose`][Self::compose].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use core::ptr::NonNull;
+        ///
+        /// type TagNonNull = tagptr::TagNonNull<i32, 2>;
+        ///
+        /// let ptr = NonNull::from(&mut 1);
+        /// assert_eq!(TagNonNull::compose_exact(ptr, 0b11), Some(TagNonNull::compose(ptr, 0b11)));
+        /// assert_eq!(TagNonNull::compose_exact(ptr, 0b101), None);
+        /// ```
+        #[inline]
+        pub fn compose_exact(ptr: NonNull<T>, tag: usize) -> Option<Self> {
+            if tag & Self::POINTER_MASK == 0 {
+                Some(Self::compose(ptr, tag))
+            } else {
+                None
+            }
+        }
+    }
+
     /// Attempts to compose a new marked pointer from a raw (non-null) `ptr` and
     /// a `tag` value.
     ///
@@ -139,6 +293,57 @@ impl<T, const N: usize> TagNonNull<T, N> {
         Self::try_compose_inner(ptr.as_ptr(), tag)
     }
 
+    /// Attempts to compose a new marked pointer from a raw `ptr` and a `tag`
+    /// value, checking that `ptr` is both non-null and well-aligned.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `ptr` is `null`, in which case a [`Null`] instance containing
+    /// the (truncated) `tag` is returned, or if `ptr` has any of its lower `N`
+    /// bits set, in which case the [`Null`] instance contains these bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ptr;
+    ///
+    /// use tagptr::Null;
+    ///
+    /// type TagNonNull = tagptr::TagNonNull<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = TagNonNull::try_from_ptr_tag(reference, 0b01).unwrap();
+    /// assert_eq!(ptr.decompose_tag(), 0b01);
+    /// assert_eq!(TagNonNull::try_from_ptr_tag(ptr::null_mut(), 0b10), Err(Null(0b10)));
+    /// ```
+    #[inline]
+    pub fn try_from_ptr_tag(ptr: *mut T, tag: usize) -> Result<Self, Null> {
+        match NonNull::new(ptr) {
+            Some(ptr) => Self::try_from_non_null_tag(ptr, tag),
+            None => Err(Null(tag & Self::TAG_MASK)),
+        }
+    }
+
+    /// Attempts to compose a new marked pointer from a non-null `ptr` and a
+    /// `tag` value, checking that `ptr` is well-aligned.
+    ///
+    /// Unlike [`try_compose`][TagNonNull::try_compose], this fails for any
+    /// mis-aligned `ptr`, not only for those that would be parsed as a marked
+    /// `null` pointer.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `ptr` has any of its lower `N` bits set, in which case a
+    /// [`Null`] instance containing these bits is returned.
+    #[inline]
+    pub fn try_from_non_null_tag(ptr: NonNull<T>, tag: usize) -> Result<Self, Null> {
+        match ptr.as_ptr().addr() & Self::TAG_MASK {
+            // SAFETY: ptr is non-null and has no bits set in its tag bits
+            0 => Ok(unsafe { Self::retag_unchecked(ptr, tag) }),
+            bits => Err(Null(bits)),
+        }
+    }
+
     /// Composes a new marked pointer from a raw (non-null) `ptr` and a `tag`
     /// value without checking if `ptr` is valid.
     ///
@@ -148,7 +353,47 @@ impl<T, const N: usize> TagNonNull<T, N> {
     /// its `N` lower bits as tag bits.
     #[inline]
     pub unsafe fn compose_unchecked(ptr: NonNull<T>, tag: usize) -> Self {
-        Self::new_unchecked(TagPtr::compose(ptr.as_ptr(), tag))
+        Self::retag_unchecked(ptr, tag)
+    }
+
+    /// Creates a new marked pointer from its raw parts, i.e., a (non-null)
+    /// `ptr` and a `tag` value, without checking if `ptr` is valid.
+    ///
+    /// This is equivalent to [`compose_unchecked`][Self::compose_unchecked]
+    /// and the inverse of [`into_raw_parts`][Self::into_raw_parts].
+    ///
+    /// # Safety
+    ///
+    /// The caller has to ensure that `ptr` has non-zero bits in its pointer
+    /// bits, i.e., that it is not mis-aligned in a way that would be parsed
+    /// as a marked `null` pointer once its `N` lower bits are considered as
+    /// tag bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ptr::NonNull;
+    ///
+    /// type TagNonNull = tagptr::TagNonNull<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// // SAFETY: a reference is always well-aligned and never null
+    /// let ptr = unsafe { TagNonNull::from_raw_parts(NonNull::from(&mut *reference), 0b11) };
+    /// assert_eq!(ptr.into_raw_parts(), (NonNull::from(reference), 0b11));
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: NonNull<T>, tag: usize) -> Self {
+        Self::compose_unchecked(ptr, tag)
+    }
+
+    /// Decomposes the marked pointer into its raw parts, i.e., the (non-null)
+    /// pointer stripped of its tag and the separated tag value.
+    ///
+    /// This is equivalent to [`decompose`][Self::decompose] and the inverse of
+    /// [`from_raw_parts`][Self::from_raw_parts].
+    #[inline]
+    pub fn into_raw_parts(self) -> (NonNull<T>, usize) {
+        self.decompose()
     }
 
     doc_comment! {
@@ -174,7 +419,7 @@ impl<T, const N: usize> TagNonNull<T, N> {
         pub fn set_tag(self, tag: usize) -> Self {
             let ptr = self.decompose_non_null();
             // SAFETY: ptr was decomposed from a valid marked non-nullable pointer
-            unsafe { Self::compose_unchecked(ptr, tag) }
+            unsafe { Self::retag_unchecked(ptr, tag) }
         }
     }
 
@@ -184,7 +429,110 @@ impl<T, const N: usize> TagNonNull<T, N> {
         pub fn update_tag(self, func: impl FnOnce(usize) -> usize) -> Self {
             let (ptr, tag) = self.decompose();
             // SAFETY: ptr was decomposed from a valid marked non-nullable pointer
-            unsafe { Self::compose_unchecked(ptr, func(tag)) }
+            unsafe { Self::retag_unchecked(ptr, func(tag)) }
+        }
+    }
+
+    doc_comment! {
+        doc_map_tag!(),
+        #[inline]
+        pub fn map_tag<U>(self, func: impl FnOnce(usize) -> U) -> (Self, U) {
+            let (inner, tag) = self.decompose();
+            (Self { inner, _marker: PhantomData }, func(tag))
+        }
+    }
+
+    doc_comment! {
+        doc_map_ptr!(),
+        ///
+        /// Panics also if the pointer returned by `func` would be parsed as a
+        /// marked `null` pointer.
+        #[inline]
+        pub fn map_ptr(self, func: impl FnOnce(NonNull<T>) -> NonNull<T>) -> Self {
+            let (ptr, tag) = self.decompose();
+            Self::try_compose_inner(func(ptr).as_ptr(), tag).expect(Self::COMPOSE_ERR_MSG)
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("`count` units of `T`"),
+        ///
+        /// Panics also if the resulting pointer would be parsed as a marked
+        /// `null` pointer.
+        #[inline]
+        pub fn wrapping_offset(self, count: isize) -> Self {
+            self.wrapping_map(|ptr| ptr.wrapping_offset(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("`count` bytes"),
+        ///
+        /// Panics also if the resulting pointer would be parsed as a marked
+        /// `null` pointer.
+        #[inline]
+        pub fn wrapping_byte_offset(self, count: isize) -> Self {
+            self.wrapping_map(|ptr| ptr.cast::<u8>().wrapping_offset(count).cast())
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("adding `count` units of `T`"),
+        ///
+        /// Panics also if the resulting pointer would be parsed as a marked
+        /// `null` pointer.
+        #[inline]
+        pub fn wrapping_add(self, count: usize) -> Self {
+            self.wrapping_map(|ptr| ptr.wrapping_add(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("subtracting `count` units of `T`"),
+        ///
+        /// Panics also if the resulting pointer would be parsed as a marked
+        /// `null` pointer.
+        #[inline]
+        pub fn wrapping_sub(self, count: usize) -> Self {
+            self.wrapping_map(|ptr| ptr.wrapping_sub(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("adding `count` bytes"),
+        ///
+        /// Panics also if the resulting pointer would be parsed as a marked
+        /// `null` pointer.
+        #[inline]
+        pub fn wrapping_byte_add(self, count: usize) -> Self {
+            self.wrapping_map(|ptr| ptr.wrapping_byte_add(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("subtracting `count` bytes"),
+        ///
+        /// Panics also if the resulting pointer would be parsed as a marked
+        /// `null` pointer.
+        #[inline]
+        pub fn wrapping_byte_sub(self, count: usize) -> Self {
+            self.wrapping_map(|ptr| ptr.wrapping_byte_sub(count))
+        }
+    }
+
+    doc_comment! {
+        doc_byte_offset!("adding `count` bytes", "byte_add"),
+        #[inline]
+        pub unsafe fn byte_add(self, count: usize) -> Self {
+            self.wrapping_map(|ptr| ptr.byte_add(count))
+        }
+    }
+
+    doc_comment! {
+        doc_byte_offset!("subtracting `count` bytes", "byte_sub"),
+        #[inline]
+        pub unsafe fn byte_sub(self, count: usize) -> Self {
+            self.wrapping_map(|ptr| ptr.byte_sub(count))
         }
     }
 
@@ -196,7 +544,7 @@ impl<T, const N: usize> TagNonNull<T, N> {
         /// `null` (neither marked nor unmarked).
         #[inline]
         pub unsafe fn add_tag(self, value: usize) -> Self {
-            Self::from_usize(self.into_usize().wrapping_add(value))
+            Self::new_unchecked(self.into_marked_ptr().add_tag(value))
         }
     }
 
@@ -208,7 +556,7 @@ impl<T, const N: usize> TagNonNull<T, N> {
         /// `null` (neither marked nor unmarked).
         #[inline]
         pub unsafe fn sub_tag(self, value: usize) -> Self {
-            Self::from_usize(self.into_usize().wrapping_sub(value))
+            Self::new_unchecked(self.into_marked_ptr().sub_tag(value))
         }
     }
 
@@ -224,7 +572,7 @@ impl<T, const N: usize> TagNonNull<T, N> {
         doc_decompose_ptr!(),
         #[inline]
         pub fn decompose_ptr(self) -> *mut T {
-            crate::decompose_ptr(self.inner.as_ptr() as usize, Self::TAG_BITS)
+            crate::decompose_ptr(self.inner.as_ptr(), Self::TAG_BITS)
         }
     }
 
@@ -237,11 +585,38 @@ impl<T, const N: usize> TagNonNull<T, N> {
         }
     }
 
+    /// Returns the [`NonNull`] pointer stripped of its tag, preserving its
+    /// provenance.
+    ///
+    /// This is equivalent to
+    /// [`decompose_non_null`][TagNonNull::decompose_non_null], but
+    /// communicates the intent of only reading through the returned pointer.
+    #[inline]
+    pub fn as_const_non_null(self) -> NonNull<T> {
+        self.decompose_non_null()
+    }
+
     doc_comment! {
         doc_decompose_tag!(),
         #[inline]
         pub fn decompose_tag(self) -> usize {
-            crate::decompose_tag::<T>(self.inner.as_ptr() as usize, Self::TAG_BITS)
+            crate::decompose_tag(self.inner.as_ptr() as usize, Self::TAG_BITS)
+        }
+    }
+
+    doc_comment! {
+        doc_pointer_eq!(),
+        #[inline]
+        pub fn pointer_eq(self, other: Self) -> bool {
+            self.decompose_ptr() == other.decompose_ptr()
+        }
+    }
+
+    doc_comment! {
+        doc_tag_eq!(),
+        #[inline]
+        pub fn tag_eq(self, other: Self) -> bool {
+            self.decompose_tag() == other.decompose_tag()
         }
     }
 
@@ -285,12 +660,171 @@ impl<T, const N: usize> TagNonNull<T, N> {
         (&mut *ptr.as_ptr(), tag)
     }
 
+    /// Converts `boxed` into a marked pointer with the given `tag`.
+    ///
+    /// The box can be reconstructed with [`into_box`][TagNonNull::into_box].
+    ///
+    /// Fails to compile if the alignment of `T` is too small for `N` tag
+    /// bits, since the tag would otherwise be stored in the address bits of
+    /// the allocation and `into_box` could not reconstruct the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagNonNull = tagptr::TagNonNull<i32, 2>;
+    ///
+    /// let ptr = TagNonNull::from_box(Box::new(1), 0b11);
+    /// assert_eq!(ptr.decompose_tag(), 0b11);
+    ///
+    /// let boxed = unsafe { ptr.into_box() };
+    /// assert_eq!(*boxed, 1);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// // `u16` has an alignment of 2 and therefore only 1 unused bit
+    /// let _ = tagptr::TagNonNull::<u16, 2>::from_box(Box::new(1), 0b01);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn from_box(boxed: Box<T>, tag: usize) -> Self {
+        crate::assert_alignment_const::<T, N>();
+        Self::compose(NonNull::from(Box::leak(boxed)), tag)
+    }
+
+    /// Converts the marked pointer back into a [`Box`], discarding the tag
+    /// value.
+    ///
+    /// # Safety
+    ///
+    /// The same safety caveats as with [`Box::from_raw`] apply, i.e., the
+    /// pointer must have been allocated by a `Box` using the global allocator
+    /// (e.g., by [`from_box`][TagNonNull::from_box]) and must not be used
+    /// again afterwards.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub unsafe fn into_box(self) -> Box<T> {
+        Box::from_raw(self.decompose_ptr())
+    }
+
+    /// Converts `boxed` into a marked pointer with the given `tag` and returns
+    /// it together with the box's allocator.
+    ///
+    /// Like [`from_box`][TagNonNull::from_box], this fails to compile if the
+    /// alignment of `T` is too small for `N` tag bits.
+    #[cfg(feature = "allocator_api")]
+    #[inline]
+    pub fn from_box_in<A: Allocator>(boxed: Box<T, A>, tag: usize) -> (Self, A) {
+        crate::assert_alignment_const::<T, N>();
+        let (ptr, alloc) = Box::into_raw_with_allocator(boxed);
+        // SAFETY: a `Box` is never null
+        (Self::compose(unsafe { NonNull::new_unchecked(ptr) }, tag), alloc)
+    }
+
+    /// Converts the marked pointer back into a [`Box`] using the given
+    /// allocator, discarding the tag value.
+    ///
+    /// # Safety
+    ///
+    /// The same safety caveats as with [`Box::from_raw_in`] apply, i.e., the
+    /// pointer must have been allocated by a `Box` using `alloc` (e.g., by
+    /// [`from_box_in`][TagNonNull::from_box_in]) and must not be used again
+    /// afterwards.
+    #[cfg(feature = "allocator_api")]
+    #[inline]
+    pub unsafe fn into_box_in<A: Allocator>(self, alloc: A) -> Box<T, A> {
+        Box::from_raw_in(self.decompose_ptr(), alloc)
+    }
+
+    doc_comment! {
+        doc_read!(),
+        #[inline]
+        pub unsafe fn read(self) -> T {
+            self.decompose_non_null().read()
+        }
+    }
+
+    doc_comment! {
+        doc_read_volatile!(),
+        #[inline]
+        pub unsafe fn read_volatile(self) -> T {
+            self.decompose_non_null().read_volatile()
+        }
+    }
+
+    doc_comment! {
+        doc_write!(),
+        #[inline]
+        pub unsafe fn write(self, val: T) {
+            self.decompose_non_null().write(val)
+        }
+    }
+
+    doc_comment! {
+        doc_write_volatile!(),
+        #[inline]
+        pub unsafe fn write_volatile(self, val: T) {
+            self.decompose_non_null().write_volatile(val)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_to!(),
+        #[inline]
+        pub unsafe fn copy_to(self, dest: NonNull<T>, count: usize) {
+            self.decompose_non_null().copy_to(dest, count)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_to_nonoverlapping!(),
+        #[inline]
+        pub unsafe fn copy_to_nonoverlapping(self, dest: NonNull<T>, count: usize) {
+            self.decompose_non_null().copy_to_nonoverlapping(dest, count)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_from!(),
+        #[inline]
+        pub unsafe fn copy_from(self, src: NonNull<T>, count: usize) {
+            self.decompose_non_null().copy_from(src, count)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_from_nonoverlapping!(),
+        #[inline]
+        pub unsafe fn copy_from_nonoverlapping(self, src: NonNull<T>, count: usize) {
+            self.decompose_non_null().copy_from_nonoverlapping(src, count)
+        }
+    }
+
+    #[inline]
+    fn wrapping_map(self, func: impl FnOnce(*mut T) -> *mut T) -> Self {
+        let (ptr, tag) = self.decompose();
+        Self::try_compose_inner(func(ptr.as_ptr()), tag).expect(Self::COMPOSE_ERR_MSG)
+    }
+
     #[inline]
     fn try_compose_inner(ptr: *mut T, tag: usize) -> Result<Self, Null> {
-        match ptr as usize & Self::POINTER_MASK {
-            0 => Err(Null(ptr as usize)),
+        match ptr.addr() & Self::POINTER_MASK {
+            0 => Err(Null(ptr.addr())),
             // SAFETY: the pointer's upper bits are non-zero,
-            _ => Ok(unsafe { Self::new_unchecked(TagPtr::compose(ptr, tag)) }),
+            _ => Ok(unsafe { Self::new_unchecked(TagPtr::new(crate::compose::<T, N>(ptr, tag))) }),
+        }
+    }
+
+    #[inline]
+    unsafe fn retag_unchecked(ptr: NonNull<T>, tag: usize) -> Self {
+        Self::new_unchecked(TagPtr::new(crate::compose::<T, N>(ptr.as_ptr(), tag)))
+    }
+
+    #[inline]
+    fn try_from_marked(marked_ptr: *mut T) -> Result<Self, Null> {
+        match marked_ptr.addr() & Self::POINTER_MASK {
+            0 => Err(Null(marked_ptr.addr())),
+            // SAFETY: the pointer's upper bits are non-zero,
+            _ => Ok(unsafe { Self::new_unchecked(TagPtr::new(marked_ptr)) }),
         }
     }
 }
@@ -301,6 +835,31 @@ impl<T, const N: usize> fmt::Debug for TagNonNull<T, N> {
     impl_debug!("TagNonNull");
 }
 
+/********** impl Display **************************************************************************/
+
+/// Formats the pointer as `{ptr:p}[tag={tag}]`, e.g., `0x7ffd6e1c[tag=1]`.
+impl<T, const N: usize> fmt::Display for TagNonNull<T, N> {
+    impl_display!();
+}
+
+/********** impl Binary ***************************************************************************/
+
+impl<T, const N: usize> fmt::Binary for TagNonNull<T, N> {
+    impl_fmt_addr!(Binary);
+}
+
+/********** impl LowerHex *************************************************************************/
+
+impl<T, const N: usize> fmt::LowerHex for TagNonNull<T, N> {
+    impl_fmt_addr!(LowerHex);
+}
+
+/********** impl UpperHex *************************************************************************/
+
+impl<T, const N: usize> fmt::UpperHex for TagNonNull<T, N> {
+    impl_fmt_addr!(UpperHex);
+}
+
 /********** impl Pointer **************************************************************************/
 
 impl<T, const N: usize> fmt::Pointer for TagNonNull<T, N> {
@@ -325,6 +884,24 @@ impl<T, const N: usize> From<&mut T> for TagNonNull<T, N> {
     }
 }
 
+/********** impl From ((NonNull<T>, usize)) *******************************************************/
+
+impl<T, const N: usize> From<(NonNull<T>, usize)> for TagNonNull<T, N> {
+    #[inline]
+    fn from((ptr, tag): (NonNull<T>, usize)) -> Self {
+        Self::compose(ptr, tag)
+    }
+}
+
+/********** impl From (TagNonNull<T, N>) for NonNull<T> *******************************************/
+
+impl<T, const N: usize> From<TagNonNull<T, N>> for NonNull<T> {
+    #[inline]
+    fn from(ptr: TagNonNull<T, N>) -> Self {
+        ptr.decompose_non_null()
+    }
+}
+
 /********** impl PartialEq ************************************************************************/
 
 impl<T, const N: usize> PartialEq for TagNonNull<T, N> {
@@ -353,6 +930,42 @@ impl<T, const N: usize> Hash for TagNonNull<T, N> {
     impl_hash!();
 }
 
+/********** impl BitAnd ***************************************************************************/
+
+impl<T, const N: usize> BitAnd<usize> for TagNonNull<T, N> {
+    impl_bit_op!(bitand, &);
+}
+
+/********** impl BitOr ****************************************************************************/
+
+impl<T, const N: usize> BitOr<usize> for TagNonNull<T, N> {
+    impl_bit_op!(bitor, |);
+}
+
+/********** impl BitXor ***************************************************************************/
+
+impl<T, const N: usize> BitXor<usize> for TagNonNull<T, N> {
+    impl_bit_op!(bitxor, ^);
+}
+
+/********** impl BitAndAssign *********************************************************************/
+
+impl<T, const N: usize> BitAndAssign<usize> for TagNonNull<T, N> {
+    impl_bit_op_assign!(bitand_assign, &);
+}
+
+/********** impl BitOrAssign **********************************************************************/
+
+impl<T, const N: usize> BitOrAssign<usize> for TagNonNull<T, N> {
+    impl_bit_op_assign!(bitor_assign, |);
+}
+
+/********** impl BitXorAssign *********************************************************************/
+
+impl<T, const N: usize> BitXorAssign<usize> for TagNonNull<T, N> {
+    impl_bit_op_assign!(bitxor_assign, ^);
+}
+
 /********** impl TryFrom (*mut T) *****************************************************************/
 
 impl<T, const N: usize> TryFrom<*mut T> for TagNonNull<T, N> {
@@ -360,7 +973,7 @@ impl<T, const N: usize> TryFrom<*mut T> for TagNonNull<T, N> {
 
     #[inline]
     fn try_from(ptr: *mut T) -> Result<Self, Self::Error> {
-        Self::try_compose_inner(ptr, 0)
+        Self::try_from_marked(ptr)
     }
 }
 
@@ -425,8 +1038,307 @@ mod tests {
         let res = TagNonNull::try_compose(dangling, 0).map(|ptr| ptr.decompose());
         assert_eq!(res, Ok((dangling, 0)));
 
+        #[allow(clippy::manual_dangling_ptr)] // an address made up of tag bits only
         let ptr = NonNull::new(0b11 as *mut i32).unwrap();
         let res = TagNonNull::try_compose(ptr, 0b11);
         assert_eq!(res, Err(Null(0b11)));
     }
+
+    #[test]
+    fn test_from_tuple() {
+        let reference = &1;
+        let ptr = NonNull::from(reference);
+        assert_eq!(TagNonNull::from((ptr, 0b10)).decompose(), (ptr, 0b10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_tuple_null() {
+        let ptr = NonNull::new(crate::TagPtr::<i32, 2>::from_usize(0b11).into_raw()).unwrap();
+        let _ = TagNonNull::from((ptr, 0));
+    }
+
+    #[test]
+    fn test_with_addr() {
+        let reference = &1;
+        let ptr = TagNonNull::compose(NonNull::from(reference), 0b01);
+
+        let res = ptr.with_addr(ptr.addr() | 0b10).map(|ptr| ptr.decompose());
+        assert_eq!(res, Ok((NonNull::from(reference), 0b11)));
+        assert_eq!(ptr.with_addr(0b10), Err(Null(0b10)));
+    }
+
+    #[test]
+    fn test_try_compose_aligned() {
+        let reference = &1u64;
+        let ptr = NonNull::from(reference);
+        let res = crate::TagNonNull::<u64, 3>::try_compose(ptr, 0b101).map(|ptr| ptr.decompose());
+        assert_eq!(res, Ok((ptr, 0b101)));
+
+        // an address with only zero bits in its upper (pointer) portion
+        let ptr = NonNull::new(crate::TagPtr::<u64, 3>::from_usize(0b100).into_raw()).unwrap();
+        let res = crate::TagNonNull::<u64, 3>::try_compose(ptr, 0b1);
+        assert_eq!(res, Err(Null(0b100)));
+    }
+
+    #[test]
+    #[should_panic(expected = "could be parsed as marked `null` pointer")]
+    fn test_compose_null() {
+        let ptr = NonNull::new(crate::TagPtr::<i32, 2>::from_usize(0b01).into_raw()).unwrap();
+        let _ = TagNonNull::compose(ptr, 0b10);
+    }
+
+    #[test]
+    fn test_new_marked() {
+        let reference = &1;
+        let marked = crate::TagPtr::compose(reference as *const i32 as *mut i32, 0b11);
+        let res = TagNonNull::new(marked).map(|ptr| ptr.decompose());
+        assert_eq!(res, Ok((NonNull::from(reference), 0b11)));
+
+        let res = TagNonNull::new(crate::TagPtr::null_with_tag(0b10));
+        assert_eq!(res, Err(Null(0b10)));
+    }
+
+    #[test]
+    fn test_tag_width() {
+        let reference = &1u64;
+        let ptr = crate::TagNonNull::<u64, 2>::compose(NonNull::from(reference), 0b10);
+
+        let wide = ptr.widen_tag::<3>();
+        assert_eq!(wide.decompose(), (NonNull::from(reference), 0b10));
+        assert_eq!(wide.set_tag(0b100).try_narrow_tag::<2>(), None);
+        let narrow = wide.try_narrow_tag::<1>();
+        assert_eq!(narrow, None);
+        assert_eq!(wide.try_narrow_tag::<2>(), Some(ptr));
+    }
+
+    #[test]
+    fn test_as_const_non_null() {
+        let reference = &1;
+        let ptr = TagNonNull::compose(NonNull::from(reference), 0b11);
+        assert_eq!(ptr.as_const_non_null(), NonNull::from(reference));
+        assert_eq!(ptr.as_const_non_null().as_ptr() as *const i32, reference as *const i32);
+    }
+
+    #[test]
+    fn test_read_write() {
+        let mut vals = [1, 2];
+        let ptr = TagNonNull::compose(NonNull::from(&mut vals[0]), 0b11);
+        let other = NonNull::from(&mut vals[1]);
+
+        unsafe {
+            ptr.write(3);
+            assert_eq!(ptr.read(), 3);
+            ptr.write_volatile(4);
+            assert_eq!(ptr.read_volatile(), 4);
+            ptr.copy_to_nonoverlapping(other, 1);
+            assert_eq!(other.read(), 4);
+            other.write(5);
+            ptr.copy_from(other, 1);
+            assert_eq!(ptr.read(), 5);
+        }
+
+        assert_eq!(ptr.decompose_tag(), 0b11);
+    }
+
+    #[test]
+    fn test_map() {
+        let array = &mut [1, 2];
+        let ptr = TagNonNull::compose(NonNull::from(&mut array[0]), 0b10);
+
+        let (cleared, flag) = ptr.map_tag(|tag| tag & 0b10 != 0);
+        assert_eq!((cleared.decompose(), flag), ((NonNull::from(&mut array[0]), 0), true));
+
+        let ptr = ptr.map_ptr(|ptr| unsafe { ptr.add(1) });
+        assert_eq!(ptr.decompose(), (NonNull::from(&mut array[1]), 0b10));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "tag bits in raw pointer must be zeroed")]
+    fn test_map_ptr_misaligned() {
+        let array = &mut [1, 2];
+        let ptr = TagNonNull::compose(NonNull::from(&mut array[0]), 0b10);
+        let _ = ptr.map_ptr(|ptr| unsafe { ptr.byte_add(1) });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_box_round_trip() {
+        use alloc::boxed::Box;
+
+        let ptr = TagNonNull::from_box(Box::new(1), 0b01);
+        let ptr = ptr.update_tag(|tag| tag + 1);
+        assert_eq!(ptr.decompose_tag(), 0b10);
+        assert_eq!(unsafe { *ptr.clear_tag().as_ref() }, 1);
+
+        let boxed = unsafe { ptr.into_box() };
+        assert_eq!(*boxed, 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_box_round_trip_min_alignment() {
+        use alloc::boxed::Box;
+
+        // `u16` has an alignment of 2, which is just sufficient for 1 tag bit
+        let ptr = crate::TagNonNull::<u16, 1>::from_box(Box::new(1), 0b1);
+        assert_eq!(ptr.decompose_tag(), 0b1);
+        let boxed = unsafe { ptr.into_box() };
+        assert_eq!(*boxed, 1);
+    }
+
+    #[cfg(feature = "allocator_api")]
+    #[test]
+    fn test_box_in_round_trip() {
+        use alloc::{alloc::Global, boxed::Box};
+
+        let (ptr, alloc) = TagNonNull::from_box_in(Box::new_in(1, Global), 0b11);
+        assert_eq!(ptr.decompose_tag(), 0b11);
+        let boxed = unsafe { ptr.into_box_in(alloc) };
+        assert_eq!(*boxed, 1);
+    }
+
+    #[test]
+    fn test_wrapping_offset() {
+        let array = &mut [1, 2, 3];
+        let ptr = TagNonNull::compose(NonNull::from(&mut array[0]), 0b11);
+
+        assert_eq!(ptr.wrapping_offset(1).decompose(), (NonNull::from(&mut array[1]), 0b11));
+        assert_eq!(ptr.wrapping_add(2).decompose(), (NonNull::from(&mut array[2]), 0b11));
+        assert_eq!(ptr.wrapping_add(2).wrapping_sub(1), ptr.wrapping_offset(1));
+        assert_eq!(ptr.wrapping_byte_offset(4), ptr.wrapping_offset(1));
+        assert_eq!(ptr.wrapping_byte_add(8).wrapping_byte_sub(4), ptr.wrapping_offset(1));
+        assert_eq!(unsafe { ptr.byte_add(8).byte_sub(4) }.decompose_tag(), 0b11);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "tag bits in raw pointer must be zeroed")]
+    fn test_wrapping_byte_offset_misaligned() {
+        let array = &mut [1, 2];
+        let ptr = TagNonNull::compose(NonNull::from(&mut array[0]), 0b10);
+        let _ = ptr.wrapping_byte_offset(1);
+    }
+
+    #[test]
+    fn test_into_non_null_strips_tag() {
+        let reference = &mut 1;
+        let ptr = TagNonNull::compose(NonNull::from(&mut *reference), 0b11);
+
+        let non_null: NonNull<i32> = ptr.into();
+        assert_eq!(non_null, NonNull::from(reference));
+        assert_eq!(TagNonNull::compose(non_null, 0), ptr.clear_tag());
+    }
+
+    #[test]
+    fn test_display() {
+        let reference = &mut 1;
+        let expected = std::format!("{:p}[tag=3]", reference as *mut i32);
+        let ptr = TagNonNull::compose(NonNull::from(&mut *reference), 0b11);
+        assert_eq!(std::format!("{}", ptr), expected);
+    }
+
+    #[test]
+    fn test_display_null() {
+        let err = TagNonNull::new(crate::TagPtr::null_with_tag(0b10)).unwrap_err();
+        assert_eq!(std::format!("{}", err), "null(tag=2)");
+    }
+
+    #[test]
+    fn test_fmt_null() {
+        let null = Null(0b1010);
+        assert_eq!(std::format!("{:x}", null), "a");
+        assert_eq!(std::format!("{:#x}", null), "0xa");
+        assert_eq!(std::format!("{:#X}", null), "0xA");
+        assert_eq!(std::format!("{:#06b}", null), "0b1010");
+        assert_eq!(std::format!("{:o}", null), "12");
+    }
+
+    #[test]
+    fn test_from_exposed_addr() {
+        let reference = &mut 1;
+        let addr = TagNonNull::compose(NonNull::from(&mut *reference), 0b01).expose_addr();
+
+        let ptr = TagNonNull::from_exposed_addr(addr).unwrap();
+        assert_eq!(unsafe { ptr.decompose_ref() }, (&1, 0b01));
+        assert_eq!(TagNonNull::from_exposed_addr(0b11), Err(Null(0b11)));
+    }
+
+    #[test]
+    fn test_raw_parts() {
+        let reference = &mut 1;
+        let non_null = NonNull::from(&mut *reference);
+
+        let ptr = unsafe { TagNonNull::from_raw_parts(non_null, 0b10) };
+        assert_eq!(ptr, TagNonNull::compose(non_null, 0b10));
+        assert_eq!(ptr.into_raw_parts(), (non_null, 0b10));
+    }
+
+    #[test]
+    fn test_raw_parts_round_trip() {
+        let reference = &mut 1;
+        let ptrs = [NonNull::from(reference), NonNull::dangling()];
+
+        for ptr in ptrs {
+            for tag in 0..=TagNonNull::TAG_MASK {
+                let raw = unsafe { TagNonNull::from_raw_parts(ptr, tag) }.into_raw_parts();
+                assert_eq!(raw, (ptr, tag));
+            }
+        }
+
+        // excess tag bits are truncated, like with `compose`
+        let raw = unsafe { TagNonNull::from_raw_parts(ptrs[1], 0b111) }.into_raw_parts();
+        assert_eq!(raw, (ptrs[1], 0b11));
+    }
+
+    #[test]
+    fn test_mask_methods() {
+        let ptr = TagNonNull::dangling();
+        assert_eq!(ptr.tag_bits_count(), 2);
+        assert_eq!(ptr.tag_mask(), TagNonNull::TAG_MASK);
+        assert_eq!(ptr.pointer_mask(), TagNonNull::POINTER_MASK);
+    }
+
+    #[test]
+    fn test_bit_ops() {
+        let reference = &mut 1;
+        let ptr = TagNonNull::from(reference);
+        assert_eq!((ptr | 0b11) & 0b01, ptr.set_tag(0b01));
+        assert_eq!(ptr | usize::MAX, ptr.set_tag(0b11));
+
+        let mut tagged = ptr;
+        tagged |= 0b10;
+        tagged ^= 0b11;
+        assert_eq!(tagged, ptr.set_tag(0b01));
+        tagged &= 0b10;
+        assert_eq!(tagged, ptr);
+    }
+
+    #[test]
+    fn test_try_from_ptr_tag() {
+        let reference = &mut 1;
+        let raw: *mut i32 = reference;
+
+        let ptr = TagNonNull::try_from_ptr_tag(raw, 0b10).unwrap();
+        assert_eq!(ptr.decompose(), (NonNull::new(raw).unwrap(), 0b10));
+        assert_eq!(TagNonNull::try_from_ptr_tag(core::ptr::null_mut(), 0b101), Err(Null(0b01)));
+
+        let misaligned = raw.wrapping_byte_add(0b01);
+        assert_eq!(TagNonNull::try_from_ptr_tag(misaligned, 0b10), Err(Null(0b01)));
+        let misaligned = NonNull::new(misaligned).unwrap();
+        assert_eq!(TagNonNull::try_from_non_null_tag(misaligned, 0), Err(Null(0b01)));
+        assert_eq!(
+            TagNonNull::try_from_non_null_tag(NonNull::new(raw).unwrap(), 0b11),
+            Ok(TagNonNull::compose(NonNull::new(raw).unwrap(), 0b11))
+        );
+    }
+
+    #[test]
+    fn test_compose_type_erased() {
+        let reference = &mut 1;
+        let erased =
+            crate::TagNonNull::<(), 2>::compose(NonNull::from(&mut *reference).cast(), 0b01);
+        assert_eq!(erased.cast::<i32>(), TagNonNull::compose(NonNull::from(reference), 0b01));
+    }
 }
@@ -0,0 +1,50 @@
+use core::mem;
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    strategy::{Map, Strategy},
+};
+
+use crate::{Null, TagNonNull, TagPtr};
+
+/********** impl Arbitrary ************************************************************************/
+
+impl<T, const N: usize> Arbitrary for TagPtr<T, N> {
+    type Parameters = ();
+    type Strategy = Map<<(usize, usize) as Arbitrary>::Strategy, fn((usize, usize)) -> Self>;
+
+    #[inline]
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<(usize, usize)>().prop_map(|(addr, tag)| {
+            // the generated address is aligned for `T` and has no bits in the tag bits
+            let addr = addr & !(mem::align_of::<T>() - 1) & Self::POINTER_MASK;
+            Self::from_usize(addr | (tag & Self::TAG_MASK))
+        })
+    }
+}
+
+impl<T, const N: usize> Arbitrary for TagNonNull<T, N> {
+    type Parameters = ();
+    type Strategy = Map<<(usize, usize) as Arbitrary>::Strategy, fn((usize, usize)) -> Self>;
+
+    #[inline]
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<(usize, usize)>().prop_map(|(addr, tag)| {
+            let addr = addr & !(mem::align_of::<T>() - 1) & Self::POINTER_MASK;
+            // replace a null address with the lowest address that is both
+            // aligned for `T` and has no bits in the tag bits
+            let addr = if addr == 0 { mem::align_of::<T>().max(Self::TAG_MASK + 1) } else { addr };
+            Self::new(TagPtr::from_usize(addr | (tag & Self::TAG_MASK))).unwrap()
+        })
+    }
+}
+
+impl Arbitrary for Null {
+    type Parameters = ();
+    type Strategy = Map<<usize as Arbitrary>::Strategy, fn(usize) -> Self>;
+
+    #[inline]
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<usize>().prop_map(Null)
+    }
+}
@@ -2,6 +2,7 @@ use core::{
     cmp, fmt,
     hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign},
     ptr::{self, NonNull},
 };
 
@@ -35,6 +36,26 @@ impl<T, const N: usize> TagPtr<T, N> {
         pub const POINTER_MASK: usize = !Self::TAG_MASK;
     }
 
+    /// Returns the number of available tag bits, i.e.,
+    /// [`TAG_BITS`][Self::TAG_BITS].
+    #[inline]
+    pub const fn tag_bits_count(self) -> usize {
+        Self::TAG_BITS
+    }
+
+    /// Returns the bit mask for the tag bits, i.e., [`TAG_MASK`][Self::TAG_MASK].
+    #[inline]
+    pub const fn tag_mask(self) -> usize {
+        Self::TAG_MASK
+    }
+
+    /// Returns the bit mask for the pointer bits, i.e.,
+    /// [`POINTER_MASK`][Self::POINTER_MASK].
+    #[inline]
+    pub const fn pointer_mask(self) -> usize {
+        Self::POINTER_MASK
+    }
+
     doc_comment! {
         doc_null!(),
         ///
@@ -54,6 +75,67 @@ impl<T, const N: usize> TagPtr<T, N> {
         }
     }
 
+    doc_comment! {
+        doc_null_with_tag!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use core::ptr;
+        ///
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let ptr = TagPtr::null_with_tag(0b10);
+        /// assert!(ptr.is_null());
+        /// assert_eq!(ptr.decompose(), (ptr::null_mut(), 0b10));
+        /// ```
+        #[inline]
+        pub const fn null_with_tag(tag: usize) -> Self {
+            Self::new(ptr::without_provenance_mut(tag & Self::TAG_MASK))
+        }
+    }
+
+    /// Creates a new pointer that is dangling but well aligned.
+    ///
+    /// Like [`NonNull::dangling`], the address is equal to the alignment of
+    /// `T`, unless `T` has insufficient alignment for `N` tag bits, in which
+    /// case the smallest address without any set tag bits is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ptr::NonNull;
+    ///
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let ptr = TagPtr::dangling();
+    /// assert_eq!(ptr.decompose(), (NonNull::dangling().as_ptr(), 0));
+    /// ```
+    #[inline]
+    pub const fn dangling() -> Self {
+        Self::new(ptr::without_provenance_mut(crate::dangling_addr::<T>(N)))
+    }
+
+    /// Creates a new pointer that is dangling but well aligned and marked
+    /// with the given `tag`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ptr::NonNull;
+    ///
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let ptr = TagPtr::dangling_with_tag(0b10);
+    /// assert_eq!(ptr.decompose(), (NonNull::dangling().as_ptr(), 0b10));
+    /// ```
+    #[inline]
+    pub const fn dangling_with_tag(tag: usize) -> Self {
+        Self::new(ptr::without_provenance_mut(
+            crate::dangling_addr::<T>(N) | (tag & Self::TAG_MASK),
+        ))
+    }
+
     doc_comment! {
         doc_new!(),
         ///
@@ -91,6 +173,26 @@ impl<T, const N: usize> TagPtr<T, N> {
         }
     }
 
+    doc_comment! {
+        doc_from_exposed_addr!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let reference = &mut 1;
+        /// let addr = TagPtr::compose(reference, 0b11).expose_addr();
+        ///
+        /// let ptr = TagPtr::from_exposed_addr(addr);
+        /// assert_eq!(unsafe { ptr.decompose_ref() }, (Some(&1), 0b11));
+        /// ```
+        #[inline]
+        pub fn from_exposed_addr(addr: usize) -> Self {
+            Self::new(ptr::with_exposed_provenance_mut(addr))
+        }
+    }
+
     doc_comment! {
         doc_into_raw!(),
         ///
@@ -115,6 +217,65 @@ impl<T, const N: usize> TagPtr<T, N> {
         }
     }
 
+    doc_comment! {
+        doc_widen_tag!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<u64, 2>;
+        ///
+        /// let reference = &mut 1;
+        /// let ptr = TagPtr::compose(reference, 0b11);
+        /// let wide: tagptr::TagPtr<u64, 3> = ptr.widen_tag();
+        /// assert_eq!(wide.decompose(), (reference as *mut _, 0b11));
+        /// ```
+        ///
+        /// ```compile_fail
+        /// let ptr = tagptr::TagPtr::<u64, 2>::null();
+        /// let _: tagptr::TagPtr<u64, 1> = ptr.widen_tag();
+        /// ```
+        #[inline]
+        pub fn widen_tag<const M: usize>(self) -> TagPtr<T, M> {
+            const { assert!(M >= N, "`M` must not be less than `N`") };
+            debug_assert_eq!(
+                self.decompose_ptr().addr() & crate::mark_mask(M),
+                0,
+                "pointer is mis-aligned for `M` tag bits"
+            );
+            TagPtr::new(self.inner)
+        }
+    }
+
+    doc_comment! {
+        doc_try_narrow_tag!(),
+        ///
+        /// # Errors
+        ///
+        /// Fails, if the current tag value does not fit into `M` bits.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<u64, 3>;
+        ///
+        /// let reference = &mut 1;
+        /// let narrow = TagPtr::compose(reference, 0b011).try_narrow_tag::<2>();
+        /// assert_eq!(narrow.map(|ptr| ptr.decompose()), Some((reference as *mut _, 0b11)));
+        ///
+        /// let narrow = TagPtr::compose(reference, 0b111).try_narrow_tag::<2>();
+        /// assert_eq!(narrow, None);
+        /// ```
+        #[inline]
+        pub fn try_narrow_tag<const M: usize>(self) -> Option<TagPtr<T, M>> {
+            const { assert!(M <= N, "`M` must not be greater than `N`") };
+            match self.decompose_tag() & !crate::mark_mask(M) {
+                0 => Some(TagPtr::new(self.inner)),
+                _ => None,
+            }
+        }
+    }
+
     doc_comment! {
         doc_into_usize!(),
         ///
@@ -132,6 +293,52 @@ impl<T, const N: usize> TagPtr<T, N> {
         }
     }
 
+    doc_comment! {
+        doc_addr!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let reference = &mut 1;
+        /// let ptr = TagPtr::compose(reference, 0b11);
+        /// assert_eq!(ptr.addr(), (reference as *mut i32).addr() | 0b11);
+        /// ```
+        #[inline]
+        pub fn addr(self) -> usize {
+            self.inner.addr()
+        }
+    }
+
+    /// Creates a new marked pointer with the given address, including any tag
+    /// bits, and the provenance of `self`.
+    ///
+    /// See [`pointer::with_addr`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = TagPtr::new(reference);
+    /// let marked = ptr.with_addr(ptr.addr() | 0b10);
+    /// assert_eq!(marked.decompose(), (reference as *mut _, 0b10));
+    /// ```
+    #[inline]
+    pub fn with_addr(self, addr: usize) -> Self {
+        Self::new(self.inner.with_addr(addr))
+    }
+
+    doc_comment! {
+        doc_expose_addr!(),
+        #[inline]
+        pub fn expose_addr(self) -> usize {
+            self.inner.expose_provenance()
+        }
+    }
+
     doc_comment! {
         doc_compose!(),
         ///
@@ -153,6 +360,82 @@ impl<T, const N: usize> TagPtr<T, N> {
         }
     }
 
+    /// Composes a new marked pointer from a raw `ptr` and a `tag` value like
+    /// [`compose`][Self::compose], but fails to compile if the alignment of `T`
+    /// is too small for `N` tag bits.
+    ///
+    /// Unlike `compose`, this can not be used with type-erased pointers such
+    /// as `TagPtr<(), N>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagPtr = tagptr::TagPtr<u64, 3>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = TagPtr::compose_checked(reference, 0b101);
+    /// assert_eq!(ptr.decompose(), (reference as *mut _, 0b101));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// // `u16` has an alignment of 2 and therefore only 1 unused bit
+    /// let _ = tagptr::TagPtr::<u16, 3>::compose_checked(&mut 1, 0b101);
+    /// ```
+    #[inline]
+    pub fn compose_checked(ptr: *mut T, tag: usize) -> Self {
+        crate::assert_alignment_const::<T, N>();
+        Self::compose(ptr, tag)
+    }
+
+    doc_comment! {
+        doc_compose_exact!(),
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let raw = &1 as *const i32 as *mut i32;
+        /// assert_eq!(TagPtr::compose_exact(raw, 0b11), Some(TagPtr::compose(raw, 0b11)));
+        /// assert_eq!(TagPtr::compose_exact(raw, 0b101), None);
+        /// ```
+        #[inline]
+        pub fn compose_exact(ptr: *mut T, tag: usize) -> Option<Self> {
+            if tag & Self::POINTER_MASK == 0 {
+                Some(Self::compose(ptr, tag))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Composes a new marked pointer from a raw `ptr` and a `tag` value in a
+    /// `const` context.
+    ///
+    /// Since the address of a pointer can not be inspected in `const`
+    /// contexts, the (truncated) tag is *added* to `ptr` instead of being
+    /// combined with a bitwise "or" as in [`compose`][Self::compose].
+    /// Both are equivalent only if `ptr` is well-aligned, i.e., has none of its
+    /// lower `N` bits set, which is not checked (even in debug builds).
+    ///
+    /// The caller must ensure `ptr` is well-aligned, otherwise the tag carries
+    /// over into the pointer bits and the resulting pointer is corrupted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagPtr = tagptr::TagPtr<u64, 3>;
+    ///
+    /// static VALUE: u64 = 1;
+    /// const PTR: TagPtr = TagPtr::compose_const(&VALUE as *const u64 as *mut u64, 0b101);
+    ///
+    /// assert_eq!(PTR.decompose(), (&VALUE as *const u64 as *mut u64, 0b101));
+    /// ```
+    #[inline]
+    pub const fn compose_const(ptr: *mut T, tag: usize) -> Self {
+        crate::assert_alignment_const::<T, N>();
+        Self::new(ptr.wrapping_byte_add(crate::mark_mask(N) & tag))
+    }
+
     /// Returns `true` if the marked pointer is `null`.
     ///
     /// # Examples
@@ -170,6 +453,44 @@ impl<T, const N: usize> TagPtr<T, N> {
         self.decompose_ptr().is_null()
     }
 
+    /// Returns `true` if the pointer stripped of its tag is properly aligned
+    /// for `T`.
+    ///
+    /// This is always the case for pointers composed from valid pointers but
+    /// not necessarily for pointers created from arbitrary integers (e.g.,
+    /// by [`from_usize`][TagPtr::from_usize]).
+    /// A `null` pointer is always aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagPtr = tagptr::TagPtr<u64, 1>;
+    ///
+    /// assert!(TagPtr::from_usize(0x1000 | 0b1).is_aligned());
+    /// assert!(!TagPtr::from_usize(0x1002 | 0b1).is_aligned());
+    /// ```
+    #[inline]
+    pub fn is_aligned(self) -> bool {
+        self.decompose_ptr().is_aligned()
+    }
+
+    /// Returns `true` if the pointer stripped of its tag is properly aligned
+    /// for `T` *and* the alignment of `T` is sufficient for storing `N` tag
+    /// bits, so no tag bit can overlap with a bit of a valid address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(tagptr::TagPtr::<u64, 3>::from_usize(0x1000 | 0b101).is_well_formed());
+    /// assert!(!tagptr::TagPtr::<u64, 1>::from_usize(0x1002).is_well_formed());
+    /// // `u64` has only 3 unused bits
+    /// assert!(!tagptr::TagPtr::<u64, 4>::from_usize(0x1000).is_well_formed());
+    /// ```
+    #[inline]
+    pub fn is_well_formed(self) -> bool {
+        crate::has_sufficient_alignment::<T>(N) && self.is_aligned()
+    }
+
     doc_comment! {
         doc_clear_tag!(),
         ///
@@ -225,7 +546,53 @@ impl<T, const N: usize> TagPtr<T, N> {
         #[inline]
         pub fn set_tag(self, tag: usize) -> Self {
             let ptr = self.decompose_ptr();
-            Self::compose(ptr, tag)
+            Self::new(crate::compose::<T, N>(ptr, tag))
+        }
+    }
+
+    /// Returns the marked pointer unchanged if it is non-null or otherwise a
+    /// `null` pointer with the given `tag`, e.g., a tombstone marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = TagPtr::compose(reference, 0b01);
+    ///
+    /// assert_eq!(ptr.or_null_with_tag(0b11), ptr);
+    /// assert_eq!(TagPtr::null().or_null_with_tag(0b11), TagPtr::null_with_tag(0b11));
+    /// ```
+    #[inline]
+    pub fn or_null_with_tag(self, tag: usize) -> Self {
+        if self.is_null() {
+            Self::null_with_tag(tag)
+        } else {
+            self
+        }
+    }
+
+    /// Sets the tag to `non_null_tag` if the pointer is non-null or otherwise
+    /// to `null_tag`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &mut 1;
+    /// let ptr = TagPtr::new(reference);
+    ///
+    /// assert_eq!(ptr.with_tag_or_null(0b01, 0b10).decompose_tag(), 0b01);
+    /// assert_eq!(TagPtr::null().with_tag_or_null(0b01, 0b10).decompose_tag(), 0b10);
+    /// ```
+    #[inline]
+    pub fn with_tag_or_null(self, non_null_tag: usize, null_tag: usize) -> Self {
+        if self.is_null() {
+            self.set_tag(null_tag)
+        } else {
+            self.set_tag(non_null_tag)
         }
     }
 
@@ -245,7 +612,123 @@ impl<T, const N: usize> TagPtr<T, N> {
         #[inline]
         pub fn update_tag(self, func: impl FnOnce(usize) -> usize) -> Self {
             let (ptr, tag) = self.decompose();
-            Self::compose(ptr, func(tag))
+            Self::new(crate::compose::<T, N>(ptr, func(tag)))
+        }
+    }
+
+    doc_comment! {
+        doc_map_tag!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let reference = &mut 1;
+        /// let ptr = TagPtr::compose(reference, 0b10);
+        ///
+        /// assert_eq!(ptr.map_tag(|tag| tag == 0b10), (TagPtr::new(reference), true));
+        /// ```
+        #[inline]
+        pub fn map_tag<U>(self, func: impl FnOnce(usize) -> U) -> (Self, U) {
+            let (ptr, tag) = self.decompose();
+            (Self::new(ptr), func(tag))
+        }
+    }
+
+    doc_comment! {
+        doc_map_ptr!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let array = &mut [1, 2];
+        /// let ptr = TagPtr::compose(&mut array[0], 0b11);
+        ///
+        /// let ptr = ptr.map_ptr(|ptr| ptr.wrapping_add(1));
+        /// assert_eq!(ptr.decompose(), (&mut array[1] as *mut _, 0b11));
+        /// ```
+        #[inline]
+        pub fn map_ptr(self, func: impl FnOnce(*mut T) -> *mut T) -> Self {
+            let (ptr, tag) = self.decompose();
+            Self::new(crate::compose::<T, N>(func(ptr), tag))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("`count` units of `T`"),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let array = &mut [1, 2, 3];
+        /// let ptr = TagPtr::compose(&mut array[2], 0b11);
+        ///
+        /// assert_eq!(ptr.wrapping_offset(-2).decompose(), (&mut array[0] as *mut _, 0b11));
+        /// ```
+        #[inline]
+        pub fn wrapping_offset(self, count: isize) -> Self {
+            self.map_ptr(|ptr| ptr.wrapping_offset(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("`count` bytes"),
+        #[inline]
+        pub fn wrapping_byte_offset(self, count: isize) -> Self {
+            self.map_ptr(|ptr| ptr.cast::<u8>().wrapping_offset(count).cast())
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("adding `count` units of `T`"),
+        #[inline]
+        pub fn wrapping_add(self, count: usize) -> Self {
+            self.map_ptr(|ptr| ptr.wrapping_add(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("subtracting `count` units of `T`"),
+        #[inline]
+        pub fn wrapping_sub(self, count: usize) -> Self {
+            self.map_ptr(|ptr| ptr.wrapping_sub(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("adding `count` bytes"),
+        #[inline]
+        pub fn wrapping_byte_add(self, count: usize) -> Self {
+            self.map_ptr(|ptr| ptr.wrapping_byte_add(count))
+        }
+    }
+
+    doc_comment! {
+        doc_wrapping_offset!("subtracting `count` bytes"),
+        #[inline]
+        pub fn wrapping_byte_sub(self, count: usize) -> Self {
+            self.map_ptr(|ptr| ptr.wrapping_byte_sub(count))
+        }
+    }
+
+    doc_comment! {
+        doc_byte_offset!("adding `count` bytes", "byte_add"),
+        #[inline]
+        pub unsafe fn byte_add(self, count: usize) -> Self {
+            self.map_ptr(|ptr| ptr.byte_add(count))
+        }
+    }
+
+    doc_comment! {
+        doc_byte_offset!("subtracting `count` bytes", "byte_sub"),
+        #[inline]
+        pub unsafe fn byte_sub(self, count: usize) -> Self {
+            self.map_ptr(|ptr| ptr.byte_sub(count))
         }
     }
 
@@ -264,7 +747,7 @@ impl<T, const N: usize> TagPtr<T, N> {
         /// ```
         #[inline]
         pub fn add_tag(self, value: usize) -> Self {
-            Self::from_usize(self.into_usize().wrapping_add(value))
+            Self::new(self.inner.wrapping_byte_add(value))
         }
     }
 
@@ -283,7 +766,7 @@ impl<T, const N: usize> TagPtr<T, N> {
         /// ```
         #[inline]
         pub fn sub_tag(self, value: usize) -> Self {
-            Self::from_usize(self.into_usize().wrapping_sub(value))
+            Self::new(self.inner.wrapping_byte_sub(value))
         }
     }
 
@@ -299,15 +782,78 @@ impl<T, const N: usize> TagPtr<T, N> {
         doc_decompose_ptr!(),
         #[inline]
         pub fn decompose_ptr(self) -> *mut T {
-            crate::decompose_ptr::<T>(self.inner as usize, Self::TAG_BITS)
+            crate::decompose_ptr(self.inner, Self::TAG_BITS)
         }
     }
 
+    /// Returns the raw `*const` pointer stripped of its tag, preserving its
+    /// provenance.
+    ///
+    /// This is equivalent to [`decompose_ptr`][TagPtr::decompose_ptr], but
+    /// communicates the intent of only reading through the returned pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TagPtr = tagptr::TagPtr<i32, 2>;
+    ///
+    /// let reference = &1;
+    /// let ptr = TagPtr::compose(reference as *const i32 as *mut i32, 0b11);
+    /// assert_eq!(ptr.as_const_ptr(), reference as *const i32);
+    /// ```
+    #[inline]
+    pub fn as_const_ptr(self) -> *const T {
+        self.decompose_ptr()
+    }
+
     doc_comment! {
         doc_decompose_tag!(),
         #[inline]
         pub fn decompose_tag(self) -> usize {
-            crate::decompose_tag::<T>(self.inner as usize, Self::TAG_BITS)
+            crate::decompose_tag(self.inner as usize, Self::TAG_BITS)
+        }
+    }
+
+    doc_comment! {
+        doc_pointer_eq!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let reference = &mut 1;
+        /// let a = TagPtr::compose(reference, 0b01);
+        /// let b = TagPtr::compose(reference, 0b10);
+        ///
+        /// assert_ne!(a, b);
+        /// assert!(a.pointer_eq(b));
+        /// ```
+        #[inline]
+        pub fn pointer_eq(self, other: Self) -> bool {
+            self.decompose_ptr() == other.decompose_ptr()
+        }
+    }
+
+    doc_comment! {
+        doc_tag_eq!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use core::ptr;
+        ///
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let a = TagPtr::compose(&mut 1, 0b01);
+        /// let b = TagPtr::compose(ptr::null_mut(), 0b01);
+        ///
+        /// assert_ne!(a, b);
+        /// assert!(a.tag_eq(b));
+        /// ```
+        #[inline]
+        pub fn tag_eq(self, other: Self) -> bool {
+            self.decompose_tag() == other.decompose_tag()
         }
     }
 
@@ -400,6 +946,84 @@ impl<T, const N: usize> TagPtr<T, N> {
     pub unsafe fn decompose_mut<'a>(self) -> (Option<&'a mut T>, usize) {
         (self.as_mut(), self.decompose_tag())
     }
+
+    doc_comment! {
+        doc_read!(),
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// type TagPtr = tagptr::TagPtr<i32, 2>;
+        ///
+        /// let mut val = 1;
+        /// let ptr = TagPtr::compose(&mut val, 0b11);
+        ///
+        /// unsafe {
+        ///     ptr.write(2);
+        ///     assert_eq!(ptr.read(), 2);
+        /// }
+        /// ```
+        #[inline]
+        pub unsafe fn read(self) -> T {
+            self.decompose_ptr().read()
+        }
+    }
+
+    doc_comment! {
+        doc_read_volatile!(),
+        #[inline]
+        pub unsafe fn read_volatile(self) -> T {
+            self.decompose_ptr().read_volatile()
+        }
+    }
+
+    doc_comment! {
+        doc_write!(),
+        #[inline]
+        pub unsafe fn write(self, val: T) {
+            self.decompose_ptr().write(val)
+        }
+    }
+
+    doc_comment! {
+        doc_write_volatile!(),
+        #[inline]
+        pub unsafe fn write_volatile(self, val: T) {
+            self.decompose_ptr().write_volatile(val)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_to!(),
+        #[inline]
+        pub unsafe fn copy_to(self, dest: *mut T, count: usize) {
+            self.decompose_ptr().copy_to(dest, count)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_to_nonoverlapping!(),
+        #[inline]
+        pub unsafe fn copy_to_nonoverlapping(self, dest: *mut T, count: usize) {
+            self.decompose_ptr().copy_to_nonoverlapping(dest, count)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_from!(),
+        #[inline]
+        pub unsafe fn copy_from(self, src: *const T, count: usize) {
+            self.decompose_ptr().copy_from(src, count)
+        }
+    }
+
+    doc_comment! {
+        doc_copy_from_nonoverlapping!(),
+        #[inline]
+        pub unsafe fn copy_from_nonoverlapping(self, src: *const T, count: usize) {
+            self.decompose_ptr().copy_from_nonoverlapping(src, count)
+        }
+    }
 }
 
 /********** impl Debug ****************************************************************************/
@@ -408,6 +1032,13 @@ impl<T, const N: usize> fmt::Debug for TagPtr<T, N> {
     impl_debug!("TagPtr");
 }
 
+/********** impl Display **************************************************************************/
+
+/// Formats the pointer as `{ptr:p}[tag={tag}]`, e.g., `0x7ffd6e1c[tag=1]`.
+impl<T, const N: usize> fmt::Display for TagPtr<T, N> {
+    impl_display!();
+}
+
 /********** impl Default **************************************************************************/
 
 impl<T, const N: usize> Default for TagPtr<T, N> {
@@ -423,6 +1054,15 @@ impl<T, const N: usize> From<*mut T> for TagPtr<T, N> {
     }
 }
 
+/********** impl From ((*mut T, usize)) ***********************************************************/
+
+impl<T, const N: usize> From<(*mut T, usize)> for TagPtr<T, N> {
+    #[inline]
+    fn from((ptr, tag): (*mut T, usize)) -> Self {
+        Self::compose(ptr, tag)
+    }
+}
+
 /********** impl From (*const T) ******************************************************************/
 
 impl<T, const N: usize> From<*const T> for TagPtr<T, N> {
@@ -432,6 +1072,15 @@ impl<T, const N: usize> From<*const T> for TagPtr<T, N> {
     }
 }
 
+/********** impl From ((*const T, usize)) *********************************************************/
+
+impl<T, const N: usize> From<(*const T, usize)> for TagPtr<T, N> {
+    #[inline]
+    fn from((ptr, tag): (*const T, usize)) -> Self {
+        Self::compose(ptr as _, tag)
+    }
+}
+
 /********** impl From (&T) ************************************************************************/
 
 impl<T, const N: usize> From<&T> for TagPtr<T, N> {
@@ -486,6 +1135,24 @@ impl<T, const N: usize> From<TagNonNull<T, N>> for TagPtr<T, N> {
     }
 }
 
+/********** impl From (TagPtr<T, N>) for *mut T ***************************************************/
+
+impl<T, const N: usize> From<TagPtr<T, N>> for *mut T {
+    #[inline]
+    fn from(ptr: TagPtr<T, N>) -> Self {
+        ptr.decompose_ptr()
+    }
+}
+
+/********** impl From (TagPtr<T, N>) for *const T *************************************************/
+
+impl<T, const N: usize> From<TagPtr<T, N>> for *const T {
+    #[inline]
+    fn from(ptr: TagPtr<T, N>) -> Self {
+        ptr.decompose_ptr()
+    }
+}
+
 /********** impl PartialEq ************************************************************************/
 
 impl<T, const N: usize> PartialEq for TagPtr<T, N> {
@@ -498,6 +1165,24 @@ impl<T, const N: usize> PartialOrd for TagPtr<T, N> {
     impl_partial_ord!();
 }
 
+/********** impl Binary ***************************************************************************/
+
+impl<T, const N: usize> fmt::Binary for TagPtr<T, N> {
+    impl_fmt_addr!(Binary);
+}
+
+/********** impl LowerHex *************************************************************************/
+
+impl<T, const N: usize> fmt::LowerHex for TagPtr<T, N> {
+    impl_fmt_addr!(LowerHex);
+}
+
+/********** impl UpperHex *************************************************************************/
+
+impl<T, const N: usize> fmt::UpperHex for TagPtr<T, N> {
+    impl_fmt_addr!(UpperHex);
+}
+
 /********** impl Pointer **************************************************************************/
 
 impl<T, const N: usize> fmt::Pointer for TagPtr<T, N> {
@@ -520,6 +1205,42 @@ impl<T, const N: usize> Hash for TagPtr<T, N> {
     impl_hash!();
 }
 
+/********** impl BitAnd ***************************************************************************/
+
+impl<T, const N: usize> BitAnd<usize> for TagPtr<T, N> {
+    impl_bit_op!(bitand, &);
+}
+
+/********** impl BitOr ****************************************************************************/
+
+impl<T, const N: usize> BitOr<usize> for TagPtr<T, N> {
+    impl_bit_op!(bitor, |);
+}
+
+/********** impl BitXor ***************************************************************************/
+
+impl<T, const N: usize> BitXor<usize> for TagPtr<T, N> {
+    impl_bit_op!(bitxor, ^);
+}
+
+/********** impl BitAndAssign *********************************************************************/
+
+impl<T, const N: usize> BitAndAssign<usize> for TagPtr<T, N> {
+    impl_bit_op_assign!(bitand_assign, &);
+}
+
+/********** impl BitOrAssign **********************************************************************/
+
+impl<T, const N: usize> BitOrAssign<usize> for TagPtr<T, N> {
+    impl_bit_op_assign!(bitor_assign, |);
+}
+
+/********** impl BitXorAssign *********************************************************************/
+
+impl<T, const N: usize> BitXorAssign<usize> for TagPtr<T, N> {
+    impl_bit_op_assign!(bitxor_assign, ^);
+}
+
 #[cfg(test)]
 mod tests {
     type TagPtr = crate::TagPtr<i32, 2>;
@@ -599,7 +1320,7 @@ mod tests {
     #[test]
     fn test_erase() {
         #[repr(align(64))]
-        struct Aligned64(i32);
+        struct Aligned64(#[allow(dead_code)] i32);
 
         let reference = &Aligned64(1);
         let ptr = crate::TagPtr::<Aligned64, 6>::from((reference, 55));
@@ -609,4 +1330,246 @@ mod tests {
 
         assert_eq!(ptr.decompose(), (reference as *const _ as *mut _, 58));
     }
+
+    #[test]
+    fn test_partial_eq() {
+        let reference = &mut 1;
+        let ptr = TagPtr::compose(reference, 0b01);
+        let null = TagPtr::compose(core::ptr::null_mut(), 0b01);
+
+        assert!(null.pointer_eq(TagPtr::null()));
+        assert!(!null.tag_eq(TagPtr::null()));
+        assert!(null.tag_eq(TagPtr::compose(core::ptr::null_mut(), 0b01)));
+
+        assert!(ptr.pointer_eq(ptr.set_tag(0b10)));
+        assert!(!ptr.tag_eq(ptr.set_tag(0b10)));
+        assert!(ptr.tag_eq(null) && !ptr.pointer_eq(null));
+
+        // full equality requires both parts to be equal
+        let other = TagPtr::compose(reference, 0b01);
+        assert!(ptr.pointer_eq(other) && ptr.tag_eq(other));
+        assert_eq!(ptr, other);
+    }
+
+    #[test]
+    fn test_dangling() {
+        use core::ptr::NonNull;
+
+        assert_eq!(TagPtr::dangling().decompose(), (NonNull::dangling().as_ptr(), 0));
+        assert_eq!(TagPtr::dangling_with_tag(0b111).decompose_tag(), 0b11);
+
+        #[repr(align(64))]
+        struct Alignment64;
+        let ptr = crate::TagPtr::<Alignment64, 3>::dangling_with_tag(0b101);
+        assert_eq!(ptr.decompose(), (NonNull::dangling().as_ptr(), 0b101));
+        // insufficient alignment must not cause any tag bits to be set
+        let ptr = crate::TagPtr::<u8, 3>::dangling();
+        assert_eq!(ptr.decompose(), (0b1000 as *mut u8, 0));
+    }
+
+    #[test]
+    fn test_from_tuple() {
+        let reference = &mut 1;
+        let raw = reference as *mut i32;
+
+        let ptr = TagPtr::from((raw, 0b10));
+        assert_eq!(ptr.decompose(), (raw, 0b10));
+        let ptr = TagPtr::from((raw as *const i32, 0b111));
+        assert_eq!(ptr.decompose(), (raw, 0b11));
+    }
+
+    #[test]
+    fn test_null_with_tag() {
+        for tag in 0..=TagPtr::TAG_MASK {
+            let ptr = TagPtr::null_with_tag(tag);
+            assert!(ptr.is_null());
+            assert_eq!(ptr.decompose_tag(), tag);
+        }
+
+        assert_eq!(TagPtr::null_with_tag(0b111), TagPtr::null_with_tag(0b11));
+    }
+
+    #[test]
+    fn test_addr() {
+        let reference = &mut 1;
+        let ptr = TagPtr::compose(reference, 0b01);
+
+        assert_eq!(ptr.addr(), ptr.into_usize());
+        assert_eq!(ptr.expose_addr(), ptr.into_usize());
+        // the provenance must be retained for dereferencing the modified pointer
+        let ptr = ptr.with_addr(ptr.addr() + 1);
+        assert_eq!(unsafe { ptr.decompose_ref() }, (Some(&1), 0b10));
+    }
+
+    #[test]
+    fn test_fmt_addr() {
+        let reference = &mut 1;
+        let ptr = TagPtr::compose(reference, 0b01);
+        let addr = reference as *mut i32 as usize | 0b01;
+
+        assert_eq!(std::format!("{:x}", ptr), std::format!("{:x}", addr));
+        assert_eq!(std::format!("{:X}", ptr), std::format!("{:X}", addr));
+        assert_eq!(std::format!("{:#b}", ptr), std::format!("{:#b}", addr));
+        assert!(std::format!("{:b}", ptr).ends_with("01"));
+        assert_eq!(std::format!("{:#06x}", TagPtr::null_with_tag(0b11)), "0x0003");
+    }
+
+    #[test]
+    fn test_read_write() {
+        let mut vals = [1, 2];
+        let ptr = TagPtr::compose(&mut vals[0], 0b11);
+        let other: *mut i32 = &mut vals[1];
+
+        unsafe {
+            ptr.write(3);
+            assert_eq!(ptr.read(), 3);
+            ptr.write_volatile(4);
+            assert_eq!(ptr.read_volatile(), 4);
+            ptr.copy_to(other, 1);
+            assert_eq!(other.read(), 4);
+            other.write(5);
+            ptr.copy_from_nonoverlapping(other, 1);
+            assert_eq!(ptr.read(), 5);
+        }
+
+        assert_eq!(ptr.decompose_tag(), 0b11);
+    }
+
+    #[test]
+    fn test_is_aligned() {
+        assert!(TagPtr::null().is_aligned());
+        assert!(TagPtr::null_with_tag(0b11).is_well_formed());
+        assert!(TagPtr::dangling_with_tag(0b11).is_well_formed());
+
+        let reference = &mut 1;
+        assert!(TagPtr::compose(reference, 0b10).is_well_formed());
+
+        // a pointer with bits set in its pointer portion below the alignment of `i32`
+        let misaligned = crate::TagPtr::<i32, 1>::from_usize(0x1000 | 0b11);
+        assert!(!misaligned.is_aligned());
+        assert!(!misaligned.is_well_formed());
+    }
+
+    #[test]
+    fn test_null_tags() {
+        let reference = &mut 1;
+        let ptr = TagPtr::compose(reference, 0b01);
+        let null = TagPtr::null_with_tag(0b01);
+
+        assert_eq!(ptr.or_null_with_tag(0b10), ptr);
+        assert_eq!(null.or_null_with_tag(0b10), TagPtr::null_with_tag(0b10));
+        assert_eq!(ptr.with_tag_or_null(0b11, 0b10), TagPtr::compose(reference, 0b11));
+        assert_eq!(null.with_tag_or_null(0b11, 0b10), TagPtr::null_with_tag(0b10));
+    }
+
+    #[test]
+    fn test_wrapping_offset() {
+        let array = &mut [1, 2, 3];
+        let ptr = TagPtr::compose(&mut array[0], 0b01);
+
+        let offset = ptr.wrapping_offset(1);
+        assert_eq!(offset.decompose(), (&mut array[1] as *mut _, 0b01));
+        assert_eq!(offset.into_usize() - ptr.into_usize(), core::mem::size_of::<i32>());
+        assert_eq!(ptr.wrapping_add(2).wrapping_sub(2), ptr);
+        assert_eq!(ptr.wrapping_byte_offset(8), ptr.wrapping_add(2));
+        assert_eq!(TagPtr::null_with_tag(0b10).wrapping_add(1).decompose_tag(), 0b10);
+        assert_eq!(ptr.wrapping_byte_add(8), ptr.wrapping_add(2));
+        assert_eq!(ptr.wrapping_byte_add(8).wrapping_byte_sub(4), offset);
+        assert_eq!(unsafe { ptr.byte_add(4) }, offset);
+        assert_eq!(unsafe { offset.byte_sub(4) }, ptr);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "tag bits in raw pointer must be zeroed")]
+    fn test_wrapping_byte_add_misaligned() {
+        let reference = &mut 1;
+        let _ = TagPtr::compose(reference, 0b01).wrapping_byte_add(2);
+    }
+
+    #[test]
+    fn test_into_raw_strips_tag() {
+        let reference = &mut 1;
+        let ptr = TagPtr::compose(reference, 0b11);
+
+        let raw: *mut i32 = ptr.into();
+        assert_eq!(raw, reference as *mut _);
+        assert_eq!(TagPtr::new(raw), ptr.clear_tag());
+
+        let raw: *const i32 = ptr.into();
+        assert_eq!(raw, reference as *const _);
+        assert_eq!(TagPtr::from(raw), ptr.clear_tag());
+    }
+
+    #[test]
+    fn test_display() {
+        let reference = &mut 1;
+        let expected = std::format!("{:p}[tag=3]", reference as *mut i32);
+        let ptr = TagPtr::compose(reference, 0b11);
+        assert_eq!(std::format!("{}", ptr), expected);
+    }
+
+    #[test]
+    fn test_compose_const() {
+        static ARRAY: [i32; 2] = [1, 2];
+        const PTR: TagPtr = TagPtr::compose_const(&ARRAY[1] as *const i32 as *mut i32, 0b110);
+
+        assert_eq!(PTR, TagPtr::compose(&ARRAY[1] as *const i32 as *mut i32, 0b10));
+        assert_eq!(unsafe { PTR.decompose_ref() }, (Some(&2), 0b10));
+    }
+
+    #[test]
+    fn test_mask_methods() {
+        let ptr = TagPtr::null();
+        assert_eq!(ptr.tag_bits_count(), 2);
+        assert_eq!(ptr.tag_mask(), TagPtr::TAG_MASK);
+        assert_eq!(ptr.pointer_mask(), TagPtr::POINTER_MASK);
+
+        let ptr = crate::TagPtr::<u64, 3>::null();
+        assert_eq!(ptr.tag_bits_count(), 3);
+        assert_eq!(ptr.tag_mask(), 0b111);
+        assert_eq!(ptr.pointer_mask(), !0b111);
+
+        let ptr = crate::TagPtr::<u8, 0>::null();
+        assert_eq!((ptr.tag_bits_count(), ptr.tag_mask(), ptr.pointer_mask()), (0, 0, !0));
+    }
+
+    #[test]
+    fn test_bit_ops() {
+        let reference = &mut 1;
+        let ptr = TagPtr::new(reference);
+        assert_eq!((ptr | 0b11) & 0b01, ptr.set_tag(0b01));
+        assert_eq!(ptr.set_tag(0b01) ^ 0b11, ptr.set_tag(0b10));
+        // bits outside of the tag bits are ignored
+        assert_eq!(ptr | usize::MAX, ptr.set_tag(0b11));
+        assert_eq!(ptr.set_tag(0b11) & !0b11, ptr);
+
+        let mut tagged = ptr;
+        tagged |= 0b10;
+        tagged ^= 0b11;
+        assert_eq!(tagged, ptr.set_tag(0b01));
+        tagged &= 0b10;
+        assert_eq!(tagged, ptr);
+    }
+
+    #[test]
+    fn test_compose_exact() {
+        let reference = &mut 1;
+        for tag in 0..=TagPtr::TAG_MASK {
+            assert_eq!(
+                TagPtr::compose_exact(reference, tag),
+                Some(TagPtr::compose(reference, tag))
+            );
+        }
+
+        assert_eq!(TagPtr::compose_exact(reference, TagPtr::TAG_MASK + 1), None);
+        assert_eq!(TagPtr::compose_exact(reference, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_compose_type_erased() {
+        let reference = &mut 1;
+        let erased = crate::TagPtr::<(), 2>::compose((reference as *mut i32).cast(), 0b01);
+        assert_eq!(erased.cast::<i32>(), TagPtr::compose(reference, 0b01));
+    }
 }
@@ -0,0 +1,276 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{TagNonNull, TaggedMut, TaggedRef};
+
+/********** impl Send + Sync **********************************************************************/
+
+unsafe impl<T: Sync, const N: usize> Send for TaggedRef<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for TaggedRef<'_, T, N> {}
+
+unsafe impl<T: Send, const N: usize> Send for TaggedMut<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for TaggedMut<'_, T, N> {}
+
+/********** impl Clone ****************************************************************************/
+
+impl<T, const N: usize> Clone for TaggedRef<'_, T, N> {
+    impl_clone!();
+}
+
+/********** impl Copy *****************************************************************************/
+
+impl<T, const N: usize> Copy for TaggedRef<'_, T, N> {}
+
+/********** impl inherent (TaggedRef) *************************************************************/
+
+impl<'a, T, const N: usize> TaggedRef<'a, T, N> {
+    doc_comment! {
+        doc_tag_bits!(),
+        pub const TAG_BITS: usize = N;
+    }
+
+    doc_comment! {
+        doc_tag_mask!(),
+        pub const TAG_MASK: usize = crate::mark_mask(Self::TAG_BITS);
+    }
+
+    /// Creates a new tagged reference from `reference` and a `tag` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reference` is mis-aligned for `N` tag bits and could be
+    /// parsed as a marked `null` pointer, see
+    /// [`TagNonNull::compose`][crate::TagNonNull::compose].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TaggedRef<'a> = tagptr::TaggedRef<'a, i32, 2>;
+    ///
+    /// let reference = &1;
+    /// let tagged = TaggedRef::new(reference, 0b11);
+    /// assert_eq!(tagged.split_tag(), (&1, 0b11));
+    /// ```
+    #[inline]
+    pub fn new(reference: &'a T, tag: usize) -> Self {
+        Self { inner: TagNonNull::compose(NonNull::from(reference), tag), _marker: PhantomData }
+    }
+
+    /// Returns the reference with its tag stripped.
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn as_ref(&self) -> &'a T {
+        // SAFETY: the pointer was created from a reference valid for 'a
+        unsafe { &*self.inner.decompose_ptr() }
+    }
+
+    doc_comment! {
+        doc_decompose_tag!(),
+        #[inline]
+        pub fn tag(self) -> usize {
+            self.inner.decompose_tag()
+        }
+    }
+
+    doc_comment! {
+        doc_set_tag!(),
+        #[inline]
+        pub fn set_tag(self, tag: usize) -> Self {
+            Self { inner: self.inner.set_tag(tag), _marker: PhantomData }
+        }
+    }
+
+    /// Splits the tagged reference, returning both the stripped reference and
+    /// the separated tag value.
+    #[inline]
+    pub fn split_tag(self) -> (&'a T, usize) {
+        (self.as_ref(), self.tag())
+    }
+
+    /// Converts `self` into a marked non-null pointer, discarding the lifetime.
+    #[inline]
+    pub fn into_marked_non_null(self) -> TagNonNull<T, N> {
+        self.inner
+    }
+}
+
+/********** impl inherent (TaggedMut) *************************************************************/
+
+impl<'a, T, const N: usize> TaggedMut<'a, T, N> {
+    doc_comment! {
+        doc_tag_bits!(),
+        pub const TAG_BITS: usize = N;
+    }
+
+    doc_comment! {
+        doc_tag_mask!(),
+        pub const TAG_MASK: usize = crate::mark_mask(Self::TAG_BITS);
+    }
+
+    /// Creates a new tagged mutable reference from `reference` and a `tag`
+    /// value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reference` is mis-aligned for `N` tag bits and could be
+    /// parsed as a marked `null` pointer, see
+    /// [`TagNonNull::compose`][crate::TagNonNull::compose].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// type TaggedMut<'a> = tagptr::TaggedMut<'a, i32, 2>;
+    ///
+    /// let mut val = 1;
+    /// let mut tagged = TaggedMut::new(&mut val, 0b11);
+    /// *tagged += 1;
+    ///
+    /// assert_eq!(tagged.split_tag(), (&mut 2, 0b11));
+    /// ```
+    #[inline]
+    pub fn new(reference: &'a mut T, tag: usize) -> Self {
+        Self { inner: TagNonNull::compose(NonNull::from(reference), tag), _marker: PhantomData }
+    }
+
+    /// Returns the mutable reference with its tag stripped, consuming `self`.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut T {
+        // SAFETY: the pointer was created from a unique reference valid for 'a
+        unsafe { &mut *self.inner.decompose_ptr() }
+    }
+
+    doc_comment! {
+        doc_decompose_tag!(),
+        #[inline]
+        pub fn tag(&self) -> usize {
+            self.inner.decompose_tag()
+        }
+    }
+
+    doc_comment! {
+        doc_set_tag!(),
+        #[inline]
+        pub fn set_tag(self, tag: usize) -> Self {
+            Self { inner: self.inner.set_tag(tag), _marker: PhantomData }
+        }
+    }
+
+    /// Splits the tagged mutable reference, returning both the stripped
+    /// reference and the separated tag value.
+    #[inline]
+    pub fn split_tag(self) -> (&'a mut T, usize) {
+        let tag = self.tag();
+        (self.into_mut(), tag)
+    }
+
+    /// Converts `self` into a marked non-null pointer, discarding the lifetime.
+    #[inline]
+    pub fn into_marked_non_null(self) -> TagNonNull<T, N> {
+        self.inner
+    }
+}
+
+/********** impl Debug ****************************************************************************/
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for TaggedRef<'_, T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (reference, tag) = self.split_tag();
+        f.debug_struct("TaggedRef").field("ref", reference).field("tag", &tag).finish()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for TaggedMut<'_, T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TaggedMut").field("ref", &&**self).field("tag", &self.tag()).finish()
+    }
+}
+
+/********** impl Deref ****************************************************************************/
+
+impl<T, const N: usize> Deref for TaggedRef<'_, T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<T, const N: usize> Deref for TaggedMut<'_, T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the pointer was created from a unique reference valid for 'a
+        unsafe { &*self.inner.decompose_ptr() }
+    }
+}
+
+/********** impl DerefMut *************************************************************************/
+
+impl<T, const N: usize> DerefMut for TaggedMut<'_, T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the pointer was created from a unique reference valid for 'a
+        unsafe { &mut *self.inner.decompose_ptr() }
+    }
+}
+
+/********** impl From (&T) ************************************************************************/
+
+impl<'a, T, const N: usize> From<&'a T> for TaggedRef<'a, T, N> {
+    #[inline]
+    fn from(reference: &'a T) -> Self {
+        Self::new(reference, 0)
+    }
+}
+
+/********** impl From (&mut T) ********************************************************************/
+
+impl<'a, T, const N: usize> From<&'a mut T> for TaggedMut<'a, T, N> {
+    #[inline]
+    fn from(reference: &'a mut T) -> Self {
+        Self::new(reference, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type TaggedRef<'a> = crate::TaggedRef<'a, i32, 2>;
+    type TaggedMut<'a> = crate::TaggedMut<'a, i32, 2>;
+
+    #[test]
+    fn test_tagged_ref() {
+        let reference = &1;
+        let tagged = TaggedRef::new(reference, 0b01);
+
+        assert_eq!(*tagged, 1);
+        assert_eq!(tagged.set_tag(0b111).split_tag(), (&1, 0b11));
+        assert_eq!(tagged.into_marked_non_null().decompose_tag(), 0b01);
+        assert!(core::ptr::eq(tagged.as_ref(), reference));
+    }
+
+    #[test]
+    fn test_tagged_mut() {
+        let mut val = 1;
+        let mut tagged = TaggedMut::new(&mut val, 0b10);
+        *tagged = 2;
+
+        let tagged = tagged.set_tag(0b01);
+        assert_eq!(tagged.tag(), 0b01);
+        *tagged.into_mut() += 1;
+        assert_eq!(val, 3);
+    }
+
+    #[test]
+    fn test_debug() {
+        let tagged = TaggedRef::new(&1, 0b11);
+        assert_eq!(std::format!("{:?}", tagged), "TaggedRef { ref: 1, tag: 3 }");
+    }
+}
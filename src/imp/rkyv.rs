@@ -0,0 +1,36 @@
+use rkyv::{primitive::ArchivedU64, rancor::Fallible, Archive, Deserialize, Place, Serialize};
+
+use crate::TagPtr;
+
+/********** impl Archive **************************************************************************/
+
+// the full bit pattern is archived as a `u64`, independent of the target's pointer width
+impl<T, const N: usize> Archive for TagPtr<T, N> {
+    type Archived = ArchivedU64;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        (self.into_usize() as u64).resolve(resolver, out);
+    }
+}
+
+/********** impl Serialize ************************************************************************/
+
+impl<S: Fallible + ?Sized, T, const N: usize> Serialize<S> for TagPtr<T, N> {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+/********** impl Deserialize **********************************************************************/
+
+// an archived address is only meaningful within the process it was created in, so
+// after deserializing an archive that was created elsewhere only the tag is meaningful
+impl<D: Fallible + ?Sized, T, const N: usize> Deserialize<TagPtr<T, N>, D> for ArchivedU64 {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<TagPtr<T, N>, D::Error> {
+        Ok(TagPtr::from_usize(self.to_native() as usize))
+    }
+}
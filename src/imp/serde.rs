@@ -0,0 +1,66 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Null, TagNonNull, TagPtr};
+
+/// The serialized representation of a [`Null`].
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "Null")]
+struct NullRepr {
+    tag: usize,
+}
+
+/********** impl Serialize ************************************************************************/
+
+impl<T, const N: usize> Serialize for TagPtr<T, N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.decompose_ptr().addr(), self.decompose_tag()).serialize(serializer)
+    }
+}
+
+impl<T, const N: usize> Serialize for TagNonNull<T, N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.into_marked_ptr().serialize(serializer)
+    }
+}
+
+impl Serialize for Null {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NullRepr { tag: self.0 }.serialize(serializer)
+    }
+}
+
+/********** impl Deserialize **********************************************************************/
+
+impl<'de, T, const N: usize> Deserialize<'de> for TagPtr<T, N> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (addr, tag) = <(usize, usize)>::deserialize(deserializer)?;
+        if addr & Self::TAG_MASK != 0 {
+            return Err(de::Error::custom("address has bits set in the tag bits"));
+        }
+
+        if tag & Self::POINTER_MASK != 0 {
+            return Err(de::Error::custom("tag value exceeds the available tag bits"));
+        }
+
+        Ok(Self::from_usize(addr | tag))
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for TagNonNull<T, N> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ptr = TagPtr::deserialize(deserializer)?;
+        Self::new(ptr).map_err(|_| de::Error::custom("address of non-nullable pointer is null"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Null {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NullRepr::deserialize(deserializer).map(|null| Null(null.tag))
+    }
+}
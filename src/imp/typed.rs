@@ -0,0 +1,323 @@
+use core::{convert::TryFrom, fmt, marker::PhantomData, sync::atomic::Ordering};
+
+use crate::{AtomicTagPtr, AtomicTypedTag, InvalidTag, TagPtr, TypedTag};
+
+/********** impl Clone ****************************************************************************/
+
+impl<T, const N: usize, E> Clone for TypedTag<T, N, E> {
+    impl_clone!();
+}
+
+/********** impl Copy *****************************************************************************/
+
+impl<T, const N: usize, E> Copy for TypedTag<T, N, E> {}
+
+/********** impl inherent (TypedTag) **************************************************************/
+
+impl<T, const N: usize, E> TypedTag<T, N, E> {
+    /// Creates a new marked pointer from an untyped marked pointer.
+    ///
+    /// Its tag is only checked for validity when decomposing the pointer.
+    #[inline]
+    pub const fn from_tag_ptr(ptr: TagPtr<T, N>) -> Self {
+        Self { inner: ptr, _marker: PhantomData }
+    }
+
+    /// Returns the underlying untyped marked pointer.
+    #[inline]
+    pub const fn into_tag_ptr(self) -> TagPtr<T, N> {
+        self.inner
+    }
+
+    /// Returns `true` if the marked pointer is `null`.
+    #[inline]
+    pub fn is_null(self) -> bool {
+        self.inner.is_null()
+    }
+
+    doc_comment! {
+        doc_decompose_ptr!(),
+        #[inline]
+        pub fn decompose_ptr(self) -> *mut T {
+            self.inner.decompose_ptr()
+        }
+    }
+}
+
+impl<T, const N: usize, E: Copy + Into<usize> + TryFrom<usize>> TypedTag<T, N, E> {
+    /// Composes a new marked pointer from a raw `ptr` and a `tag` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics in *debug builds only*, if `ptr` is not well aligned or if the
+    /// bit representation of `tag` does not fit into `N` bits.
+    #[inline]
+    pub fn compose(ptr: *mut T, tag: E) -> Self {
+        let tag = tag.into();
+        debug_assert_eq!(tag & TagPtr::<T, N>::POINTER_MASK, 0, "tag value exceeds `N` bits");
+        Self::from_tag_ptr(TagPtr::compose(ptr, tag))
+    }
+
+    /// Composes a new `null` pointer with the given `tag` value.
+    #[inline]
+    pub fn null_with_tag(tag: E) -> Self {
+        Self::compose(core::ptr::null_mut(), tag)
+    }
+
+    /// Decomposes the marked pointer, returning the raw pointer and the
+    /// separated tag value.
+    ///
+    /// # Errors
+    ///
+    /// Fails, if the stored tag bits do not correspond to a valid `E`.
+    #[inline]
+    pub fn decompose(self) -> Result<(*mut T, E), InvalidTag> {
+        Ok((self.decompose_ptr(), self.decompose_tag()?))
+    }
+
+    /// Decomposes the marked pointer, returning the raw pointer and the
+    /// separated tag value, assuming the tag is valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the stored tag bits do not correspond to a valid `E`.
+    /// This can not happen for pointers created by
+    /// [`compose`][TypedTag::compose], as long as `E`'s conversions to and
+    /// from `usize` round-trip and fit into the `N` tag bits.
+    #[inline]
+    pub fn decompose_unchecked(self) -> (*mut T, E) {
+        match self.decompose() {
+            Ok(res) => res,
+            Err(InvalidTag(tag)) => panic!("invalid tag value {}", tag),
+        }
+    }
+
+    /// Decomposes the marked pointer, returning only the separated tag value.
+    ///
+    /// # Errors
+    ///
+    /// Fails, if the stored tag bits do not correspond to a valid `E`.
+    #[inline]
+    pub fn decompose_tag(self) -> Result<E, InvalidTag> {
+        let tag = self.inner.decompose_tag();
+        E::try_from(tag).map_err(|_| InvalidTag(tag))
+    }
+
+    /// Returns the marked pointer with its tag replaced by `tag`.
+    #[inline]
+    pub fn set_tag(self, tag: E) -> Self {
+        Self::compose(self.decompose_ptr(), tag)
+    }
+}
+
+/********** impl Debug (TypedTag) *****************************************************************/
+
+impl<T, const N: usize, E> fmt::Debug for TypedTag<T, N, E>
+where
+    E: Copy + fmt::Debug + Into<usize> + TryFrom<usize>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tag = self.decompose_tag();
+        f.debug_struct("TypedTag").field("ptr", &self.decompose_ptr()).field("tag", &tag).finish()
+    }
+}
+
+/********** impl PartialEq (TypedTag) *************************************************************/
+
+impl<T, const N: usize, E> PartialEq for TypedTag<T, N, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+/********** impl Eq (TypedTag) ********************************************************************/
+
+impl<T, const N: usize, E> Eq for TypedTag<T, N, E> {}
+
+/********** impl inherent (AtomicTypedTag) ********************************************************/
+
+impl<T, const N: usize, E> AtomicTypedTag<T, N, E> {
+    doc_comment! {
+        doc_atomic_new!(),
+        #[inline]
+        pub const fn new(ptr: TypedTag<T, N, E>) -> Self {
+            Self { inner: AtomicTagPtr::new(ptr.inner), _marker: PhantomData }
+        }
+    }
+
+    doc_comment! {
+        doc_atomic_into_inner!(),
+        #[inline]
+        pub fn into_inner(self) -> TypedTag<T, N, E> {
+            TypedTag::from_tag_ptr(self.inner.into_inner())
+        }
+    }
+
+    /// Loads the value of the atomic marked pointer.
+    ///
+    /// See [`AtomicTagPtr::load`] for the possible values of `order`.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> TypedTag<T, N, E> {
+        TypedTag::from_tag_ptr(self.inner.load(order))
+    }
+
+    /// Stores a value into the atomic marked pointer.
+    ///
+    /// See [`AtomicTagPtr::store`] for the possible values of `order`.
+    #[inline]
+    pub fn store(&self, ptr: TypedTag<T, N, E>, order: Ordering) {
+        self.inner.store(ptr.inner, order)
+    }
+
+    /// Stores a value into the atomic marked pointer and returns the previous
+    /// value.
+    #[inline]
+    pub fn swap(&self, ptr: TypedTag<T, N, E>, order: Ordering) -> TypedTag<T, N, E> {
+        TypedTag::from_tag_ptr(self.inner.swap(ptr.inner, order))
+    }
+
+    /// Stores a value into the pointer if the current value is the same as
+    /// `current`.
+    ///
+    /// See [`AtomicTagPtr::compare_exchange`] for details.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: TypedTag<T, N, E>,
+        new: TypedTag<T, N, E>,
+        order: (Ordering, Ordering),
+    ) -> Result<TypedTag<T, N, E>, TypedTag<T, N, E>> {
+        self.inner
+            .compare_exchange(current.inner, new.inner, order)
+            .map(TypedTag::from_tag_ptr)
+            .map_err(TypedTag::from_tag_ptr)
+    }
+
+    /// Fetches the value, and applies a function to it that returns an
+    /// optional new value.
+    ///
+    /// See [`AtomicTagPtr::fetch_update`] for details.
+    #[inline]
+    pub fn fetch_update<F>(
+        &self,
+        order: (Ordering, Ordering),
+        mut func: F,
+    ) -> Result<TypedTag<T, N, E>, TypedTag<T, N, E>>
+    where
+        F: FnMut(TypedTag<T, N, E>) -> Option<TypedTag<T, N, E>>,
+    {
+        self.inner
+            .fetch_update(order, |ptr| func(TypedTag::from_tag_ptr(ptr)).map(|ptr| ptr.inner))
+            .map(TypedTag::from_tag_ptr)
+            .map_err(TypedTag::from_tag_ptr)
+    }
+}
+
+/********** impl Debug (AtomicTypedTag) ***********************************************************/
+
+impl<T, const N: usize, E> fmt::Debug for AtomicTypedTag<T, N, E>
+where
+    E: Copy + fmt::Debug + Into<usize> + TryFrom<usize>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ptr = self.load(Ordering::Relaxed);
+        f.debug_struct("AtomicTypedTag")
+            .field("ptr", &ptr.decompose_ptr())
+            .field("tag", &ptr.decompose_tag())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{convert::TryFrom, ptr, sync::atomic::Ordering};
+
+    use crate::InvalidTag;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum State {
+        Free,
+        Marked,
+        Deleted,
+    }
+
+    impl From<State> for usize {
+        fn from(state: State) -> usize {
+            state as usize
+        }
+    }
+
+    impl TryFrom<usize> for State {
+        type Error = ();
+
+        fn try_from(tag: usize) -> Result<Self, ()> {
+            match tag {
+                0 => Ok(State::Free),
+                1 => Ok(State::Marked),
+                2 => Ok(State::Deleted),
+                _ => Err(()),
+            }
+        }
+    }
+
+    type AtomicTypedTag = crate::AtomicTypedTag<i32, 2, State>;
+    type TagPtr = crate::TagPtr<i32, 2>;
+    type TypedTag = crate::TypedTag<i32, 2, State>;
+
+    #[test]
+    fn test_decompose() {
+        let reference = &mut 1;
+        let ptr = TypedTag::compose(reference, State::Deleted);
+        assert_eq!(ptr.decompose(), Ok((reference as *mut _, State::Deleted)));
+        assert_eq!(ptr.set_tag(State::Free).decompose_tag(), Ok(State::Free));
+        assert_eq!(
+            TypedTag::null_with_tag(State::Marked).decompose(),
+            Ok((ptr::null_mut(), State::Marked))
+        );
+    }
+
+    #[test]
+    fn test_invalid_tag() {
+        let reference = &mut 1;
+        let ptr = TypedTag::from_tag_ptr(TagPtr::compose(reference, 0b11));
+        assert_eq!(ptr.decompose(), Err(InvalidTag(0b11)));
+        assert_eq!(ptr.decompose_ptr(), reference as *mut _);
+    }
+
+    #[test]
+    fn test_decompose_unchecked() {
+        let reference = &mut 1;
+        let ptr = TypedTag::compose(reference, State::Marked);
+        assert_eq!(ptr.decompose_unchecked(), (reference as *mut _, State::Marked));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid tag value 3")]
+    fn test_decompose_unchecked_invalid_tag() {
+        let reference = &mut 1;
+        let ptr = TypedTag::from_tag_ptr(TagPtr::compose(reference, 0b11));
+        let _ = ptr.decompose_unchecked();
+    }
+
+    #[test]
+    fn test_atomic() {
+        let reference = &mut 1;
+        let atomic = AtomicTypedTag::new(TypedTag::compose(reference, State::Free));
+        let order = (Ordering::Relaxed, Ordering::Relaxed);
+
+        let curr = TypedTag::compose(reference, State::Free);
+        let new = TypedTag::compose(reference, State::Marked);
+        assert_eq!(atomic.compare_exchange(curr, new, order), Ok(curr));
+        assert_eq!(atomic.compare_exchange(curr, new, order), Err(new));
+
+        let res = atomic.fetch_update(order, |ptr| match ptr.decompose_tag() {
+            Ok(State::Marked) => Some(ptr.set_tag(State::Deleted)),
+            _ => None,
+        });
+        assert_eq!(res, Ok(new));
+        assert_eq!(atomic.into_inner().decompose_tag(), Ok(State::Deleted));
+    }
+}
@@ -0,0 +1,157 @@
+use core::fmt;
+
+use crate::{TagNonNull, TagWeak};
+
+/********** impl Clone ****************************************************************************/
+
+impl<T, const N: usize> Clone for TagWeak<T, N> {
+    impl_clone!();
+}
+
+/********** impl Copy *****************************************************************************/
+
+impl<T, const N: usize> Copy for TagWeak<T, N> {}
+
+/********** impl inherent *************************************************************************/
+
+impl<T, const N: usize> TagWeak<T, N> {
+    doc_comment! {
+        doc_tag_bits!(),
+        pub const TAG_BITS: usize = N;
+    }
+
+    doc_comment! {
+        doc_tag_mask!(),
+        pub const TAG_MASK: usize = crate::mark_mask(Self::TAG_BITS);
+    }
+
+    doc_comment! {
+        doc_ptr_mask!(),
+        pub const POINTER_MASK: usize = !Self::TAG_MASK;
+    }
+
+    /// Creates a new weak marked pointer from `ptr`.
+    #[inline]
+    pub const fn new(ptr: TagNonNull<T, N>) -> Self {
+        Self { inner: ptr }
+    }
+
+    /// Returns the marked non-null pointer, if `is_alive` returns `true` when
+    /// called with the pointer stripped of its tag.
+    ///
+    /// The liveness check is entirely up to the caller, e.g., by attempting to
+    /// increment a reference count that has not yet dropped to zero.
+    #[inline]
+    pub fn upgrade(&self, is_alive: impl FnOnce(*const T) -> bool) -> Option<TagNonNull<T, N>> {
+        if is_alive(self.inner.decompose_ptr()) {
+            Some(self.inner)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the marked non-null pointer without checking if the pointed-to
+    /// value is still alive.
+    ///
+    /// Note that the returned pointer may dangle, so the caller has to ensure
+    /// that the pointed-to value has not been reclaimed before dereferencing
+    /// it.
+    #[inline]
+    pub const fn into_non_null(self) -> TagNonNull<T, N> {
+        self.inner
+    }
+
+    doc_comment! {
+        doc_decompose_tag!(),
+        #[inline]
+        pub fn decompose_tag(self) -> usize {
+            self.inner.decompose_tag()
+        }
+    }
+
+    doc_comment! {
+        doc_set_tag!(),
+        #[inline]
+        pub fn set_tag(self, tag: usize) -> Self {
+            Self { inner: self.inner.set_tag(tag) }
+        }
+    }
+}
+
+/********** impl Debug ****************************************************************************/
+
+impl<T, const N: usize> fmt::Debug for TagWeak<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (ptr, tag) = self.inner.decompose();
+        f.debug_struct("TagWeak").field("ptr", &ptr).field("tag", &tag).finish()
+    }
+}
+
+/********** impl From (TagNonNull<T, N>) **********************************************************/
+
+impl<T, const N: usize> From<TagNonNull<T, N>> for TagWeak<T, N> {
+    #[inline]
+    fn from(ptr: TagNonNull<T, N>) -> Self {
+        Self::new(ptr)
+    }
+}
+
+/********** impl PartialEq ************************************************************************/
+
+impl<T, const N: usize> PartialEq for TagWeak<T, N> {
+    impl_partial_eq!();
+}
+
+/********** impl Eq *******************************************************************************/
+
+impl<T, const N: usize> Eq for TagWeak<T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use core::{cell::Cell, ptr::NonNull};
+
+    type TagNonNull = crate::TagNonNull<Node, 1>;
+    type TagWeak = crate::TagWeak<Node, 1>;
+
+    #[repr(align(2))]
+    struct Node {
+        refs: Cell<usize>,
+    }
+
+    fn try_acquire(node: *const Node) -> bool {
+        let node = unsafe { &*node };
+        match node.refs.get() {
+            0 => false,
+            refs => {
+                node.refs.set(refs + 1);
+                true
+            }
+        }
+    }
+
+    #[test]
+    fn test_upgrade() {
+        let node = &Node { refs: Cell::new(1) };
+        let weak = TagWeak::new(TagNonNull::compose(NonNull::from(node), 1));
+
+        let strong = weak.upgrade(try_acquire).unwrap();
+        assert_eq!(strong.decompose(), (NonNull::from(node), 1));
+        assert_eq!(node.refs.get(), 2);
+
+        node.refs.set(0);
+        assert!(weak.upgrade(try_acquire).is_none());
+        assert_eq!(node.refs.get(), 0);
+    }
+
+    #[test]
+    fn test_tag() {
+        let node = &Node { refs: Cell::new(1) };
+        let weak = TagWeak::new(TagNonNull::compose(NonNull::from(node), 0));
+
+        let weak = weak.set_tag(1);
+        assert_eq!(weak.decompose_tag(), 1);
+        assert_eq!(weak.into_non_null().decompose_tag(), 1);
+        assert_eq!(weak, TagWeak::from(TagNonNull::compose(NonNull::from(node), 1)));
+    }
+}
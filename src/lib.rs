@@ -32,8 +32,26 @@
 //! The resulting type would consider the first actual bit of the pointer to be
 //! part of its tag and return a potentially corrupted pointer in methods such
 //! as [`decompose`][TagPtr::decompose].
-//! The [`has_sufficient_alignment`] and [`assert_alignment`] functions can be
-//! used to explicitly check for or assert this property.
+//! The [`has_sufficient_alignment`], [`assert_alignment`] and
+//! [`assert_alignment_const`] functions and the
+//! [`const_assert_sufficient_alignment`] macro can be used to explicitly check
+//! for or assert this property, the latter two at compile time:
+//!
+//! ```
+//! #[repr(align(8))]
+//! struct Node(u8);
+//!
+//! tagptr::const_assert_sufficient_alignment!(Node, 3);
+//! ```
+//!
+//! Composing a pointer and a tag with [`TagPtr::compose_checked`] or
+//! [`TagNonNull::compose_checked`] rejects such a type at compile time:
+//!
+//! ```compile_fail
+//! let reference = &mut 1u16;
+//! let _ = tagptr::TagPtr::<u16, 3>::compose_checked(reference, 0b101);
+//! ```
+//!
 //! There is, however, one exception where using an otherwise ill-formed tag
 //! pointer type is valid:
 //! After composing a well-formed tag pointer instance (e.g., `TagPtr<u64, 3>`)
@@ -41,6 +59,48 @@
 //! and the same number of tag bits such as `TagPtr<(), 3>` for the purpose of
 //! type-erasure.
 //!
+//! # Features
+//!
+//! All features are disabled by default.
+//!
+//! - `alloc`: enables conversions between [`TagNonNull`] and `Box` and provides
+//!   the owning [`TaggedBox`] type.
+//! - `allocator_api` (requires nightly): enables conversions between
+//!   [`TagNonNull`] and `Box`es with custom allocators.
+//! - `arbitrary`: implements `Arbitrary` for [`TagPtr`], [`TagNonNull`] and
+//!   [`Null`] for fuzz testing.
+//! - `bytemuck`: implements `Zeroable` for [`TagPtr`] and [`AtomicTagPtr`] and
+//!   `ZeroableInOption` for [`TagNonNull`].
+//!   `Pod` is implemented for [`TagPtr`] only if it is implemented for
+//!   `*mut T`, i.e., if `bytemuck`'s own `unsound_ptr_pod_impl` feature is
+//!   enabled.
+//! - `loom`: provides the [`loom`][mod@loom] module with an [`AtomicTagPtr`]
+//!   variant backed by `loom`'s atomics for model checking.
+//! - `mmio`: provides unsafe volatile (non-atomic) loads and stores for
+//!   [`AtomicTagPtr`] for accessing marked pointers in memory-mapped I/O
+//!   registers.
+//! - `portable-atomic`: backs [`AtomicTagPtr`] with the `portable-atomic`
+//!   crate's `AtomicPtr` instead of [`core`]'s, which allows using it on
+//!   targets without native pointer-sized atomics (e.g., `thumbv6m-none-eabi`).
+//!   Since the type of the underlying atomic pointer changes, this replaces
+//!   `as_atomic_ptr` and `from_atomic_ptr` with `as_portable_atomic_ptr` and
+//!   `from_portable_atomic_ptr`.
+//! - `proptest`: implements `Arbitrary` for [`TagPtr`], [`TagNonNull`] and
+//!   [`Null`], generating pointers with well-aligned addresses and arbitrary
+//!   tags.
+//! - `rkyv`: implements `Archive`, `Serialize` and `Deserialize` for
+//!   [`TagPtr`], archiving its full bit pattern as a `u64`.
+//! - `serde`: implements `Serialize` and `Deserialize` for [`TagPtr`],
+//!   [`TagNonNull`] and [`Null`].
+//!   Pointers are serialized as a tuple of their untagged address and their tag
+//!   value, so only the tag is meaningful after deserializing in a different
+//!   process.
+//! - `std`: implements `std::error::Error` for [`Null`] and [`InvalidTag`].
+//! - `zerocopy`: implements `FromBytes` and `IntoBytes` for [`Null`].
+//!   Like `zerocopy` itself does for raw pointers, [`TagPtr`] only implements
+//!   `FromZeros` (and `TryFromBytes`, accepting only zeroed bytes), since
+//!   pointers can not soundly be created from arbitrary bytes.
+//!
 //! # Example
 //!
 //! Storing a boolean status flag alongside the pointer to a mutable `u64`:
@@ -58,20 +118,56 @@
 //! ```
 
 #![no_std]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 
 #[macro_use]
 mod macros;
 
 mod imp {
+    mod aba;
+    #[cfg(feature = "arbitrary")]
+    mod arbitrary;
     mod atomic;
+    mod backoff;
+    #[cfg(feature = "alloc")]
+    mod boxed;
+    mod by_ptr;
+    #[cfg(feature = "bytemuck")]
+    mod bytemuck;
     mod non_null;
+    #[cfg(feature = "proptest")]
+    mod proptest;
     mod ptr;
+    mod reference;
+    #[cfg(feature = "rkyv")]
+    mod rkyv;
+    #[cfg(feature = "serde")]
+    mod serde;
+    mod typed;
+    mod weak;
 }
 
-use core::{marker::PhantomData, mem, ptr::NonNull, sync::atomic::AtomicUsize};
+#[cfg(feature = "loom")]
+pub mod loom;
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Add, Sub},
+    ptr::NonNull,
+};
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::AtomicPtr;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::AtomicPtr;
 
 // *************************************************************************************************
 // AtomicTagPtr (impl in "imp/atomic.rs")
@@ -81,18 +177,96 @@ use core::{marker::PhantomData, mem, ptr::NonNull, sync::atomic::AtomicUsize};
 /// use up to `N` of its lower bits to store additional information (the *tag*).
 ///
 /// This type has the same in-memory representation as a `*mut T`.
-/// It is mostly identical to [`AtomicPtr`][atomic], except that all of its
-/// methods take or return a [`TagPtr`] instead of `*mut T`.
+/// It is mostly identical to [`AtomicPtr`], except that all of its methods
+/// take or return a [`TagPtr`] instead of `*mut T`.
 /// See the [crate][crate] level documentation for restrictions on the value of
 /// `N`.
 ///
-/// [atomic]: core::sync::atomic::AtomicPtr
+/// The formatting trait implementations (e.g., [`Debug`][core::fmt::Debug])
+/// load the current value with [`Relaxed`][core::sync::atomic::Ordering::Relaxed]
+/// ordering, so formatting a pointer does not synchronize with other threads.
 #[repr(transparent)]
 pub struct AtomicTagPtr<T, const N: usize> {
-    inner: AtomicUsize,
+    inner: AtomicPtr<T>,
     _marker: PhantomData<*mut T>,
 }
 
+// *************************************************************************************************
+// AbaPtr (impl in "imp/aba.rs")
+// *************************************************************************************************
+
+/// An atomic raw pointer which uses its `N` lower bits as a version counter
+/// for preventing the ABA problem.
+///
+/// Every successful [`compare_exchange_ptr`][AbaPtr::compare_exchange_ptr]
+/// increments the counter (wrapping around after `2^N` increments), so a
+/// pointer that was removed and re-inserted in the meantime is not mistaken for
+/// an unchanged value, unless the counter has wrapped around to the same value.
+/// See the [crate][crate] level documentation for restrictions on the value of
+/// `N`.
+///
+/// # Example
+///
+/// A (leaking) lock-free stack, which uses the version counter to detect
+/// concurrent pops and pushes of the same node:
+///
+/// ```
+/// use core::{ptr, sync::atomic::Ordering::{Acquire, Relaxed, Release}};
+///
+/// use tagptr::AbaPtr;
+///
+/// #[repr(align(8))]
+/// struct Node {
+///     value: i32,
+///     next: *mut Node,
+/// }
+///
+/// struct Stack {
+///     head: AbaPtr<Node, 3>,
+/// }
+///
+/// impl Stack {
+///     fn push(&self, node: *mut Node) {
+///         loop {
+///             let (head, aba) = self.head.load(Relaxed);
+///             unsafe { (*node).next = head };
+///             if self.head.compare_exchange_ptr(head, aba, node, (Release, Relaxed)).is_ok() {
+///                 return;
+///             }
+///         }
+///     }
+///
+///     // popped nodes are never freed, so reading `next` is always valid
+///     fn pop(&self) -> Option<*mut Node> {
+///         loop {
+///             let (head, aba) = self.head.load(Acquire);
+///             if head.is_null() {
+///                 return None;
+///             }
+///
+///             let next = unsafe { (*head).next };
+///             if self.head.compare_exchange_ptr(head, aba, next, (Acquire, Relaxed)).is_ok() {
+///                 return Some(head);
+///             }
+///         }
+///     }
+/// }
+///
+/// let stack = Stack { head: AbaPtr::null() };
+/// let a = &mut Node { value: 1, next: ptr::null_mut() };
+/// let b = &mut Node { value: 2, next: ptr::null_mut() };
+///
+/// stack.push(a);
+/// stack.push(b);
+/// assert_eq!(stack.pop().map(|node| unsafe { (*node).value }), Some(2));
+/// // every successful exchange has incremented the version counter
+/// assert_eq!(stack.head.load(Relaxed), (a as *mut _, 0b11));
+/// ```
+#[repr(transparent)]
+pub struct AbaPtr<T, const N: usize> {
+    inner: AtomicTagPtr<T, N>,
+}
+
 // *************************************************************************************************
 // TagPtr (impl in "imp/ptr.rs")
 // *************************************************************************************************
@@ -103,6 +277,10 @@ pub struct AtomicTagPtr<T, const N: usize> {
 /// This type has the same in-memory representation as a `*mut T`.
 /// See the [crate][crate] level documentation for restrictions on the value of
 /// `N`.
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromZeros, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct TagPtr<T, const N: usize> {
     inner: *mut T,
@@ -137,6 +315,306 @@ pub struct TagNonNull<T, const N: usize> {
     _marker: PhantomData<()>,
 }
 
+// *************************************************************************************************
+// TaggedRef (impl in "imp/reference.rs")
+// *************************************************************************************************
+
+/// A shared reference type like `&'a T` which can use up to `N` of its lower
+/// bits to store additional information (the *tag*).
+///
+/// This type has the same in-memory representation as a `&'a T`.
+/// Unlike the tagged raw pointer types, a tagged reference is always valid
+/// for the lifetime `'a` and can hence be dereferenced safely.
+/// See the [crate][crate] level documentation for restrictions on the value of
+/// `N`.
+///
+/// # Examples
+///
+/// The lifetime of a tagged reference is bound to the referenced value:
+///
+/// ```compile_fail
+/// type TaggedRef<'a> = tagptr::TaggedRef<'a, i32, 2>;
+///
+/// let tagged = {
+///     let val = 1;
+///     TaggedRef::new(&val, 0b11)
+/// };
+///
+/// assert_eq!(*tagged, 1);
+/// ```
+#[repr(transparent)]
+pub struct TaggedRef<'a, T, const N: usize> {
+    inner: TagNonNull<T, N>,
+    _marker: PhantomData<&'a T>,
+}
+
+// *************************************************************************************************
+// TaggedMut (impl in "imp/reference.rs")
+// *************************************************************************************************
+
+/// A mutable reference type like `&'a mut T` which can use up to `N` of its
+/// lower bits to store additional information (the *tag*).
+///
+/// This type has the same in-memory representation as a `&'a mut T`.
+/// See the [crate][crate] level documentation for restrictions on the value of
+/// `N`.
+///
+/// # Examples
+///
+/// A tagged mutable reference borrows the referenced value exclusively:
+///
+/// ```compile_fail
+/// type TaggedMut<'a> = tagptr::TaggedMut<'a, i32, 2>;
+///
+/// let mut val = 1;
+/// let tagged = TaggedMut::new(&mut val, 0b11);
+/// val = 2;
+///
+/// assert_eq!(*tagged, 2);
+/// ```
+#[repr(transparent)]
+pub struct TaggedMut<'a, T, const N: usize> {
+    inner: TagNonNull<T, N>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// *************************************************************************************************
+// TaggedBox (impl in "imp/boxed.rs")
+// *************************************************************************************************
+
+/// An owning pointer type like `Box<T>` which can use up to `N` of its lower
+/// bits to store additional information (the *tag*).
+///
+/// This type has the same in-memory representation as a `Box<T>` and drops
+/// the owned value and deallocates its memory when it is dropped.
+/// See the [crate][crate] level documentation for restrictions on the value of
+/// `N`.
+///
+/// # Examples
+///
+/// ```
+/// type TaggedBox = tagptr::TaggedBox<i32, 2>;
+///
+/// let mut boxed = TaggedBox::new(1, 0b11);
+/// *boxed += 1;
+///
+/// assert_eq!((*boxed, boxed.tag()), (2, 0b11));
+/// ```
+#[cfg(feature = "alloc")]
+#[repr(transparent)]
+pub struct TaggedBox<T, const N: usize> {
+    inner: TagNonNull<T, N>,
+    _marker: PhantomData<alloc::boxed::Box<T>>,
+}
+
+// *************************************************************************************************
+// TagWeak (impl in "imp/weak.rs")
+// *************************************************************************************************
+
+/// A non-nullable tagged raw pointer type to a value that may be reclaimed
+/// concurrently at any time, e.g., when its (intrusive) reference count drops
+/// to zero.
+///
+/// Unlike [`Weak`](https://doc.rust-lang.org/alloc/sync/struct.Weak.html),
+/// this type does not keep the allocation alive and does not track liveness
+/// by itself, but merely communicates that the pointer may dangle.
+/// A [`TagNonNull`] can only be obtained from it through
+/// [`upgrade`][TagWeak::upgrade] by providing a liveness check or through
+/// [`into_non_null`][TagWeak::into_non_null], which may return a dangling
+/// pointer.
+///
+/// # Examples
+///
+/// ```
+/// use core::ptr::NonNull;
+///
+/// type TagNonNull = tagptr::TagNonNull<i32, 2>;
+/// type TagWeak = tagptr::TagWeak<i32, 2>;
+///
+/// let reference = &mut 1;
+/// let weak = TagWeak::new(TagNonNull::compose(NonNull::from(&mut *reference), 0b01));
+///
+/// assert!(weak.upgrade(|_| false).is_none());
+/// assert_eq!(weak.upgrade(|_| true).unwrap().decompose_tag(), 0b01);
+/// ```
+#[repr(transparent)]
+pub struct TagWeak<T, const N: usize> {
+    inner: TagNonNull<T, N>,
+}
+
+// *************************************************************************************************
+// ByPtr (impl in "imp/by_ptr.rs")
+// *************************************************************************************************
+
+/// A wrapper for [`TagPtr`] and [`TagNonNull`] that compares and hashes only
+/// the pointer stripped of its tag, e.g., for use as a `HashMap` key.
+///
+/// # Examples
+///
+/// ```
+/// use tagptr::ByPtr;
+///
+/// type TagPtr = tagptr::TagPtr<i32, 2>;
+///
+/// let reference = &mut 1;
+/// let (a, b) = (TagPtr::compose(reference, 0b01), TagPtr::compose(reference, 0b10));
+///
+/// assert_ne!(a, b);
+/// assert_eq!(ByPtr(a), ByPtr(b));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct ByPtr<P>(pub P);
+
+// *************************************************************************************************
+// TypedTag (impl in "imp/typed.rs")
+// *************************************************************************************************
+
+/// A tagged raw pointer type like [`TagPtr`], which stores a value of the
+/// user-defined type `E` (e.g., an `enum`) in its `N` tag bits.
+///
+/// This type has the same in-memory representation as a `*mut T`.
+/// The tag type is converted to and from its bit representation using its
+/// `Into<usize>` and `TryFrom<usize>` implementations, so a stored bit
+/// pattern that does not correspond to a valid `E` is rejected when the
+/// pointer is decomposed.
+/// See the [crate][crate] level documentation for restrictions on the value of
+/// `N`.
+///
+/// # Examples
+///
+/// ```
+/// use core::convert::TryFrom;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum NodeState {
+///     Free,
+///     Marked,
+///     Deleted,
+///     Reserved,
+/// }
+///
+/// impl From<NodeState> for usize {
+///     fn from(state: NodeState) -> usize {
+///         state as usize
+///     }
+/// }
+///
+/// impl TryFrom<usize> for NodeState {
+///     type Error = ();
+///
+///     fn try_from(tag: usize) -> Result<Self, ()> {
+///         match tag {
+///             0 => Ok(NodeState::Free),
+///             1 => Ok(NodeState::Marked),
+///             2 => Ok(NodeState::Deleted),
+///             3 => Ok(NodeState::Reserved),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// type TypedTag = tagptr::TypedTag<u64, 2, NodeState>;
+///
+/// let reference = &mut 1;
+/// let ptr = TypedTag::compose(reference, NodeState::Marked);
+/// assert_eq!(ptr.decompose(), Ok((reference as *mut _, NodeState::Marked)));
+/// ```
+#[repr(transparent)]
+pub struct TypedTag<T, const N: usize, E> {
+    inner: TagPtr<T, N>,
+    _marker: PhantomData<E>,
+}
+
+// *************************************************************************************************
+// AtomicTypedTag (impl in "imp/typed.rs")
+// *************************************************************************************************
+
+/// An atomic [`TypedTag`], which can be safely shared between threads.
+///
+/// This type has the same in-memory representation as a `*mut T`.
+#[repr(transparent)]
+pub struct AtomicTypedTag<T, const N: usize, E> {
+    inner: AtomicTagPtr<T, N>,
+    _marker: PhantomData<E>,
+}
+
+// *************************************************************************************************
+// SpinBackoff (impl in "imp/backoff.rs")
+// *************************************************************************************************
+
+/// An exponential backoff strategy for retrying failed compare-and-swap
+/// operations in a loop.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::Ordering;
+///
+/// use tagptr::SpinBackoff;
+///
+/// type AtomicTagPtr = tagptr::AtomicTagPtr<i32, 2>;
+///
+/// let ptr = AtomicTagPtr::null();
+/// let mut backoff = SpinBackoff::new();
+///
+/// let mut curr = ptr.load(Ordering::Relaxed);
+/// loop {
+///     let new = curr.add_tag(1);
+///     match ptr.compare_exchange_weak(curr, new, (Ordering::Relaxed, Ordering::Relaxed)) {
+///         Ok(_) => break,
+///         Err(actual) => {
+///             curr = actual;
+///             backoff.snooze();
+///         }
+///     }
+/// }
+///
+/// assert_eq!(ptr.load(Ordering::Relaxed).decompose_tag(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpinBackoff {
+    spins: u32,
+    max_spin: u32,
+}
+
+// *************************************************************************************************
+// InvalidTag
+// *************************************************************************************************
+
+/// An error type for tag bit patterns that do not correspond to a valid value
+/// of a [`TypedTag`]'s tag type.
+///
+/// The contained `usize` is the invalid tag value.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct InvalidTag(pub usize);
+
+/********** impl inherent *************************************************************************/
+
+impl InvalidTag {
+    /// Returns the invalid tag value.
+    #[inline]
+    pub fn tag(self) -> usize {
+        self.0
+    }
+}
+
+/********** impl Display **************************************************************************/
+
+/// Formats the error as `invalid tag value {tag}`.
+impl fmt::Display for InvalidTag {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid tag value {}", self.0)
+    }
+}
+
+/********** impl Error ****************************************************************************/
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidTag {}
+
 // *************************************************************************************************
 // Null
 // *************************************************************************************************
@@ -145,6 +623,10 @@ pub struct TagNonNull<T, const N: usize> {
 ///
 /// The contained `usize` is the value of the pointer's tag.
 #[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct Null(pub usize);
 
@@ -156,8 +638,163 @@ impl Null {
     pub fn tag(self) -> usize {
         self.0
     }
+
+    /// Returns a `Null` with the tag value replaced by `tag`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tagptr::Null;
+    ///
+    /// assert_eq!(Null(0b01).with_tag(0b10), Null(0b10));
+    /// ```
+    #[inline]
+    pub fn with_tag(self, tag: usize) -> Self {
+        Self(tag)
+    }
+
+    /// Returns a `Null` with the tag value transformed by `func`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tagptr::Null;
+    ///
+    /// assert_eq!(Null(0b01).map(|tag| tag << 1), Null(0b10));
+    /// ```
+    #[inline]
+    pub fn map(self, func: impl FnOnce(usize) -> usize) -> Self {
+        Self(func(self.0))
+    }
+
+    /// Returns a `Null` with the tag value incremented by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the tag value overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tagptr::Null;
+    ///
+    /// assert_eq!(Null(1).increment(), Null(2));
+    /// ```
+    #[inline]
+    pub fn increment(self) -> Self {
+        self + 1
+    }
+
+    /// Returns a `Null` with the tag value decremented by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the tag value underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tagptr::Null;
+    ///
+    /// assert_eq!(Null(2).decrement(), Null(1));
+    /// ```
+    #[inline]
+    pub fn decrement(self) -> Self {
+        self - 1
+    }
+}
+
+/********** impl Add ******************************************************************************/
+
+impl Add<usize> for Null {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: usize) -> Self {
+        Self(self.0 + rhs)
+    }
+}
+
+/********** impl Sub ******************************************************************************/
+
+impl Sub<usize> for Null {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: usize) -> Self {
+        Self(self.0 - rhs)
+    }
+}
+
+/********** impl From (usize) *********************************************************************/
+
+impl From<usize> for Null {
+    #[inline]
+    fn from(tag: usize) -> Self {
+        Self(tag)
+    }
+}
+
+/********** impl From (Null) for usize ************************************************************/
+
+impl From<Null> for usize {
+    #[inline]
+    fn from(null: Null) -> Self {
+        null.0
+    }
+}
+
+/********** impl Display **************************************************************************/
+
+/// Formats the `null` pointer as `null(tag={tag})`.
+impl fmt::Display for Null {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "null(tag={})", self.0)
+    }
+}
+
+/********** impl Binary ***************************************************************************/
+
+impl fmt::Binary for Null {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+/********** impl Octal ****************************************************************************/
+
+impl fmt::Octal for Null {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+/********** impl LowerHex *************************************************************************/
+
+impl fmt::LowerHex for Null {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
 }
 
+/********** impl UpperHex *************************************************************************/
+
+impl fmt::UpperHex for Null {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+/********** impl Error ****************************************************************************/
+
+#[cfg(feature = "std")]
+impl std::error::Error for Null {}
+
 /********** public functions **********************************************************************/
 
 /// Returns `true` if the alignment of `T` is large enough so a pointer to an
@@ -167,21 +804,52 @@ pub const fn has_sufficient_alignment<T>(tag_bits: usize) -> bool {
     lower_bits::<T>() >= tag_bits
 }
 
-/// Asserts that the alignment of `U` is large enough so a pointer to an
+/// Asserts that the alignment of `T` is large enough so a pointer to an
 /// instance may store `N` tag bits.
 ///
+/// When evaluated in a `const` context, a failed assertion results in a
+/// compile time error.
+/// See also [`const_assert_sufficient_alignment`].
+///
 /// # Panics
 ///
-/// This function panics if the alignment of `U` is insufficient for storing
+/// This function panics if the alignment of `T` is insufficient for storing
 /// `N` tag bits.
 #[inline]
-pub fn assert_alignment<T, const N: usize>() {
+pub const fn assert_alignment<T, const N: usize>() {
     assert!(
         has_sufficient_alignment::<T>(N),
         "the respective type has insufficient alignment for storing N tag bits"
     );
 }
 
+/// Asserts at compile time that the alignment of `T` is large enough so a
+/// pointer to an instance may store `N` tag bits.
+///
+/// Unlike [`assert_alignment`], a failed assertion always results in a compile
+/// time error, even when called at runtime, and unlike
+/// [`const_assert_sufficient_alignment`], this can be used in generic code.
+///
+/// # Examples
+///
+/// ```
+/// fn compose<T, const N: usize>(ptr: *mut T, tag: usize) -> tagptr::TagPtr<T, N> {
+///     tagptr::assert_alignment_const::<T, N>();
+///     tagptr::TagPtr::compose(ptr, tag)
+/// }
+///
+/// let _ = compose::<u64, 3>(&mut 1, 0b101);
+/// ```
+///
+/// ```compile_fail
+/// // `u16` has an alignment of 2 and therefore only 1 unused bit
+/// tagptr::assert_alignment_const::<u16, 2>();
+/// ```
+#[inline(always)]
+pub const fn assert_alignment_const<T, const N: usize>() {
+    const { assert!(has_sufficient_alignment::<T>(N), "`N` exceeds available tag bits for `T`") };
+}
+
 /********** helper functions **********************************************************************/
 
 /// Composes the given `ptr` with `tag` and returns the composed marked pointer
@@ -193,21 +861,34 @@ pub fn assert_alignment<T, const N: usize>() {
 /// contains any bits in its lower bits reserved for the tag value.
 #[inline(always)]
 fn compose<T, const N: usize>(ptr: *mut T, tag: usize) -> *mut T {
-    debug_assert_eq!(ptr as usize & mark_mask(N), 0, "tag bits in raw pointer must be zeroed");
-    ((ptr as usize) | (mark_mask(N) & tag)) as *mut _
+    debug_assert_eq!(ptr.addr() & mark_mask(N), 0, "tag bits in raw pointer must be zeroed");
+    ptr.map_addr(|addr| addr | (mark_mask(N) & tag))
 }
 
-/// Decomposes the integer representation of a `ptr` for a given number
-/// of `tag_bits` into only a raw pointer stripped of its tag.
+/// Returns the integer representation of a dangling but well-aligned pointer
+/// to type `T` that has none of its lower `tag_bits` set.
+#[inline(always)]
+const fn dangling_addr<T>(tag_bits: usize) -> usize {
+    let alignment = mem::align_of::<T>();
+    let tag_mask = mark_mask(tag_bits);
+    if alignment > tag_mask {
+        alignment
+    } else {
+        tag_mask + 1
+    }
+}
+
+/// Decomposes a marked `ptr` for a given number of `tag_bits` into only a raw
+/// pointer stripped of its tag, preserving the pointer's provenance.
 #[inline(always)]
-const fn decompose_ptr<T>(ptr: usize, tag_bits: usize) -> *mut T {
-    (ptr & !mark_mask(tag_bits)) as *mut _
+fn decompose_ptr<T>(ptr: *mut T, tag_bits: usize) -> *mut T {
+    ptr.map_addr(|addr| addr & !mark_mask(tag_bits))
 }
 
 /// Decomposes the integer representation of a `ptr` for a given number
 /// of `tag_bits` into only a separated tag value.
 #[inline(always)]
-const fn decompose_tag<T>(ptr: usize, tag_bits: usize) -> usize {
+const fn decompose_tag(ptr: usize, tag_bits: usize) -> usize {
     ptr & mark_mask(tag_bits)
 }
 
@@ -0,0 +1,280 @@
+//! An atomic tagged pointer type backed by `loom`'s instrumented
+//! atomics for model checking concurrent algorithms.
+//!
+//! The [`AtomicTagPtr`] in this module mirrors [`crate::AtomicTagPtr`] and
+//! can be swapped in for it in tests, e.g., by way of a `cfg`-dependent import:
+//!
+//! ```ignore
+//! #[cfg(loom)]
+//! use tagptr::loom::AtomicTagPtr;
+//! #[cfg(not(loom))]
+//! use tagptr::AtomicTagPtr;
+//! ```
+//!
+//! Due to the restrictions of `loom`'s atomics, its constructors are not
+//! `const` and [`with_mut`][AtomicTagPtr::with_mut] replaces `get_mut`.
+//! All read-modify-write operations are implemented in terms of
+//! [`fetch_update`][AtomicTagPtr::fetch_update], which is indistinguishable
+//! for the model checker.
+
+use core::{fmt, marker::PhantomData};
+
+use ::loom::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::TagPtr;
+
+/// A raw pointer type which can be safely shared between threads and which can
+/// use up to `N` of its lower bits to store additional information (the *tag*),
+/// backed by a `loom` [`AtomicPtr`].
+///
+/// See [`crate::AtomicTagPtr`] for the documentation of all methods.
+pub struct AtomicTagPtr<T, const N: usize> {
+    inner: AtomicPtr<T>,
+    _marker: PhantomData<*mut T>,
+}
+
+/********** impl Send + Sync **********************************************************************/
+
+unsafe impl<T, const N: usize> Send for AtomicTagPtr<T, N> {}
+unsafe impl<T, const N: usize> Sync for AtomicTagPtr<T, N> {}
+
+/********** impl inherent *************************************************************************/
+
+impl<T, const N: usize> AtomicTagPtr<T, N> {
+    doc_comment! {
+        doc_tag_bits!(),
+        pub const TAG_BITS: usize = N;
+    }
+
+    doc_comment! {
+        doc_tag_mask!(),
+        pub const TAG_MASK: usize = crate::mark_mask(Self::TAG_BITS);
+    }
+
+    doc_comment! {
+        doc_ptr_mask!(),
+        pub const POINTER_MASK: usize = !Self::TAG_MASK;
+    }
+
+    doc_comment! {
+        doc_null!(),
+        #[inline]
+        pub fn null() -> Self {
+            Self::new(TagPtr::null())
+        }
+    }
+
+    doc_comment! {
+        doc_null_with_tag!(),
+        #[inline]
+        pub fn null_with_tag(tag: usize) -> Self {
+            Self::new(TagPtr::null_with_tag(tag))
+        }
+    }
+
+    doc_comment! {
+        doc_atomic_new!(),
+        #[inline]
+        pub fn new(marked_ptr: TagPtr<T, N>) -> Self {
+            Self { inner: AtomicPtr::new(marked_ptr.into_raw()), _marker: PhantomData }
+        }
+    }
+
+    doc_comment! {
+        doc_atomic_into_inner!(),
+        #[inline]
+        pub fn into_inner(self) -> TagPtr<T, N> {
+            TagPtr::new(self.inner.into_inner())
+        }
+    }
+
+    /// Calls `func` with a mutable reference to the underlying marked pointer.
+    #[inline]
+    pub fn with_mut<R>(&mut self, func: impl FnOnce(&mut TagPtr<T, N>) -> R) -> R {
+        self.inner.with_mut(|ptr| {
+            let mut marked_ptr = TagPtr::new(*ptr);
+            let res = func(&mut marked_ptr);
+            *ptr = marked_ptr.into_raw();
+            res
+        })
+    }
+
+    /// Loads the value of the atomic marked pointer.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> TagPtr<T, N> {
+        TagPtr::new(self.inner.load(order))
+    }
+
+    /// Loads the tag bits of the atomic marked pointer.
+    #[inline]
+    pub fn load_tag(&self, order: Ordering) -> usize {
+        self.load(order).decompose_tag()
+    }
+
+    /// Stores a value into the atomic marked pointer.
+    #[inline]
+    pub fn store(&self, ptr: TagPtr<T, N>, order: Ordering) {
+        self.inner.store(ptr.into_raw(), order)
+    }
+
+    /// Stores `tag` into the atomic marked pointer, leaving the pointer bits
+    /// unchanged.
+    #[inline]
+    pub fn store_tag(&self, tag: usize, order: Ordering) {
+        let _ = self.fetch_set_tag(tag, order);
+    }
+
+    /// Stores `tag` into the atomic marked pointer, leaving the pointer bits
+    /// unchanged, and returns the previous tag value.
+    #[inline]
+    pub fn fetch_set_tag(&self, tag: usize, order: Ordering) -> usize {
+        self.fetch_rmw(order, |ptr| ptr.set_tag(tag)).decompose_tag()
+    }
+
+    /// Stores a value into the atomic marked pointer and returns the previous
+    /// value.
+    #[inline]
+    pub fn swap(&self, ptr: TagPtr<T, N>, order: Ordering) -> TagPtr<T, N> {
+        TagPtr::new(self.inner.swap(ptr.into_raw(), order))
+    }
+
+    /// Stores a value into the pointer if the current value is the same as
+    /// `current`.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: TagPtr<T, N>,
+        new: TagPtr<T, N>,
+        (success, failure): (Ordering, Ordering),
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>> {
+        self.inner
+            .compare_exchange(current.into_raw(), new.into_raw(), success, failure)
+            .map(TagPtr::new)
+            .map_err(TagPtr::new)
+    }
+
+    /// Stores a value into the pointer if the current value is the same as
+    /// `current`, possibly failing spuriously.
+    #[inline]
+    pub fn compare_exchange_weak(
+        &self,
+        current: TagPtr<T, N>,
+        new: TagPtr<T, N>,
+        (success, failure): (Ordering, Ordering),
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>> {
+        self.inner
+            .compare_exchange_weak(current.into_raw(), new.into_raw(), success, failure)
+            .map(TagPtr::new)
+            .map_err(TagPtr::new)
+    }
+
+    /// Fetches the value, and applies a function to it that returns an
+    /// optional new value.
+    #[inline]
+    pub fn fetch_update<F>(
+        &self,
+        (set_order, fetch_order): (Ordering, Ordering),
+        mut func: F,
+    ) -> Result<TagPtr<T, N>, TagPtr<T, N>>
+    where
+        F: FnMut(TagPtr<T, N>) -> Option<TagPtr<T, N>>,
+    {
+        self.inner
+            .fetch_update(set_order, fetch_order, |curr| {
+                func(TagPtr::new(curr)).map(TagPtr::into_raw)
+            })
+            .map(TagPtr::new)
+            .map_err(TagPtr::new)
+    }
+
+    /// Adds `value` to the current tag value, returning the previous marked
+    /// pointer.
+    #[inline]
+    pub fn fetch_add(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        self.fetch_rmw(order, |ptr| TagPtr::new(ptr.into_raw().wrapping_byte_add(value)))
+    }
+
+    /// Subtracts `value` from the current tag value, returning the previous
+    /// marked pointer.
+    #[inline]
+    pub fn fetch_sub(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        self.fetch_rmw(order, |ptr| TagPtr::new(ptr.into_raw().wrapping_byte_sub(value)))
+    }
+
+    /// Performs a bitwise "or" of `value` with the current tag value, returning
+    /// the previous marked pointer.
+    #[inline]
+    pub fn fetch_or(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
+        self.fetch_rmw(order, |ptr| ptr.update_tag(|tag| tag | value))
+    }
+
+    /// Performs a bitwise "and" of `value` with the current tag value,
+    /// returning the previous marked pointer.
+    #[inline]
+    pub fn fetch_and(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
+        self.fetch_rmw(order, |ptr| ptr.update_tag(|tag| tag & value))
+    }
+
+    /// Performs a bitwise "xor" of `value` with the current tag value,
+    /// returning the previous marked pointer.
+    #[inline]
+    pub fn fetch_xor(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
+        self.fetch_rmw(order, |ptr| ptr.update_tag(|tag| tag ^ value))
+    }
+
+    /// Performs a bitwise "nand" of `value` with the current tag value,
+    /// returning the previous marked pointer.
+    #[inline]
+    pub fn fetch_nand(&self, value: usize, order: Ordering) -> TagPtr<T, N> {
+        debug_assert!(value <= Self::TAG_MASK, "`value` exceeds tag bits (would corrupt pointer)");
+        self.fetch_rmw(order, |ptr| ptr.update_tag(|tag| !(tag & value)))
+    }
+
+    #[inline]
+    fn fetch_rmw(
+        &self,
+        order: Ordering,
+        mut func: impl FnMut(TagPtr<T, N>) -> TagPtr<T, N>,
+    ) -> TagPtr<T, N> {
+        match self.fetch_update((order, Ordering::Relaxed), |ptr| Some(func(ptr))) {
+            Ok(prev) | Err(prev) => prev,
+        }
+    }
+}
+
+/********** impl Debug ****************************************************************************/
+
+impl<T, const N: usize> fmt::Debug for AtomicTagPtr<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (ptr, tag) = self.load(Ordering::Relaxed).decompose();
+        f.debug_struct("AtomicTagPtr").field("ptr", &ptr).field("tag", &tag).finish()
+    }
+}
+
+/********** impl Default **************************************************************************/
+
+impl<T, const N: usize> Default for AtomicTagPtr<T, N> {
+    impl_default!();
+}
+
+/********** impl From (*mut T) ********************************************************************/
+
+impl<T, const N: usize> From<*mut T> for AtomicTagPtr<T, N> {
+    #[inline]
+    fn from(ptr: *mut T) -> Self {
+        Self::new(ptr.into())
+    }
+}
+
+/********** impl From (TagPtr<T, N>) **************************************************************/
+
+impl<T, const N: usize> From<TagPtr<T, N>> for AtomicTagPtr<T, N> {
+    #[inline]
+    fn from(ptr: TagPtr<T, N>) -> Self {
+        Self::new(ptr)
+    }
+}
@@ -1,11 +1,43 @@
 #[macro_use]
 mod doc;
 
+/// Asserts at compile time that the alignment of a type is large enough so a
+/// pointer to an instance may store the given number of tag bits.
+///
+/// The macro can be invoked wherever an item is allowed, e.g., at the top of a
+/// module next to the definition of the respective type.
+///
+/// # Examples
+///
+/// ```
+/// tagptr::const_assert_sufficient_alignment!(u64, 3);
+/// ```
+///
+/// ```compile_fail
+/// // `u32` has an alignment of 4 and therefore only 2 unused bits
+/// tagptr::const_assert_sufficient_alignment!(u32, 3);
+/// ```
+#[macro_export]
+macro_rules! const_assert_sufficient_alignment {
+    ($ty:ty, $tag_bits:expr) => {
+        const _: () = assert!(
+            $crate::has_sufficient_alignment::<$ty>($tag_bits),
+            concat!(
+                "type `",
+                stringify!($ty),
+                "` has insufficient alignment for storing ",
+                stringify!($tag_bits),
+                " tag bits"
+            )
+        );
+    };
+}
+
 macro_rules! impl_clone {
     () => {
         #[inline]
         fn clone(&self) -> Self {
-            Self { inner: self.inner, _marker: PhantomData }
+            *self
         }
     };
 }
@@ -20,6 +52,16 @@ macro_rules! impl_debug {
     };
 }
 
+macro_rules! impl_display {
+    () => {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let (ptr, tag) = self.decompose();
+            write!(f, "{:p}[tag={}]", ptr, tag)
+        }
+    };
+}
+
 macro_rules! impl_default {
     () => {
         #[inline]
@@ -38,6 +80,15 @@ macro_rules! impl_pointer {
     };
 }
 
+macro_rules! impl_fmt_addr {
+    ($fmt_trait:ident) => {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            fmt::$fmt_trait::fmt(&self.addr(), f)
+        }
+    };
+}
+
 macro_rules! impl_partial_eq {
     () => {
         #[inline]
@@ -51,7 +102,7 @@ macro_rules! impl_partial_ord {
     () => {
         #[inline]
         fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-            self.inner.partial_cmp(&other.inner)
+            Some(self.cmp(other))
         }
     };
 }
@@ -73,3 +124,23 @@ macro_rules! impl_hash {
         }
     };
 }
+
+macro_rules! impl_bit_op {
+    ($fn_name:ident, $op:tt) => {
+        type Output = Self;
+
+        #[inline]
+        fn $fn_name(self, rhs: usize) -> Self {
+            self.update_tag(|tag| tag $op rhs)
+        }
+    };
+}
+
+macro_rules! impl_bit_op_assign {
+    ($fn_name:ident, $op:tt) => {
+        #[inline]
+        fn $fn_name(&mut self, rhs: usize) {
+            *self = self.update_tag(|tag| tag $op rhs);
+        }
+    };
+}
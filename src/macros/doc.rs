@@ -1,4 +1,4 @@
-/// All macros for generating documentation.
+//! All macros for generating documentation.
 
 /// A macro for generating arbitrary documented code items
 macro_rules! doc_comment {
@@ -39,6 +39,14 @@ macro_rules! doc_null {
     };
 }
 
+macro_rules! doc_null_with_tag {
+    () => {
+        "Creates a new `null` pointer marked with the given `tag`.\n\n\
+        Excess bits in `tag` that do not fit into the available tag bits are \
+        silently truncated."
+    };
+}
+
 macro_rules! doc_new {
     () => {
         "Creates a new unmarked pointer."
@@ -66,18 +74,78 @@ macro_rules! doc_into_usize {
     };
 }
 
+macro_rules! doc_addr {
+    () => {
+        "Returns the address portion of the pointer, including any tag bits.\n\n\
+        Unlike [`into_usize`][Self::into_usize], this does not expose the \
+        pointer's provenance, see [`pointer::addr`] for details."
+    };
+}
+
+macro_rules! doc_expose_addr {
+    () => {
+        "Exposes the provenance of the pointer and returns its address \
+        portion, including any tag bits.\n\n\
+        See [`pointer::expose_provenance`] for details."
+    };
+}
+
+macro_rules! doc_from_exposed_addr {
+    () => {
+        "Creates a new pointer from the address of a previously exposed \
+        potentially marked pointer, including any tag bits, and picks up its \
+        exposed provenance.\n\n\
+        This is the counterpart to [`expose_addr`][Self::expose_addr], see \
+        [`ptr::with_exposed_provenance_mut`][core::ptr::with_exposed_provenance_mut] \
+        for details."
+    };
+}
+
 macro_rules! doc_cast {
     () => {
         "Casts to a pointer of another type."
     };
 }
 
+macro_rules! doc_widen_tag {
+    () => {
+        "Converts the pointer into a pointer with `M` instead of `N` tag bits, \
+        preserving both its address and its tag value.\n\n\
+        Failing to pick an `M` at least as large as `N` results in a compile \
+        time error.\n\n\
+        # Panics\n\n\
+        Panics in *debug builds only*, if the pointer is not well aligned for \
+        `M` tag bits, i.e., if the address contains set bits that would be \
+        considered to be part of the tag after the conversion."
+    };
+}
+
+macro_rules! doc_try_narrow_tag {
+    () => {
+        "Attempts to convert the pointer into a pointer with `M` instead of `N` \
+        tag bits, preserving both its address and its tag value.\n\n\
+        Failing to pick an `M` at most as large as `N` results in a compile time \
+        error."
+    };
+}
+
 macro_rules! doc_compose {
     () => {
         "Composes a new marked pointer from a raw `ptr` and a `tag` value.\n\n\
         The supplied `ptr` is assumed to be well-aligned (i.e. has no tag bits \
         set) and calling this function may lead to unexpected results when \
-        this is not the case."
+        this is not the case.\n\n\
+        Any bits of `tag` exceeding the `N` available tag bits are silently \
+        truncated, use [`compose_exact`][Self::compose_exact] to detect this."
+    };
+}
+
+macro_rules! doc_compose_exact {
+    () => {
+        "Composes a new marked pointer from a raw `ptr` and a `tag` value, if \
+        the `tag` fits into the `N` available tag bits.\n\n\
+        Returns `None` if `tag` has any bits set that would otherwise be \
+        truncated by [`compose`][Self::compose].\n"
     };
 }
 
@@ -142,7 +210,8 @@ macro_rules! doc_split_tag {
     };
     () => {
         "Splits the tag value from the marked pointer, returning both the cleared pointer and the \
-        separated tag value.\n\n"
+        separated tag value.\n\n\
+        Use [`decompose`][Self::decompose] to obtain the bare (unwrapped) pointer instead.\n\n"
     };
 }
 
@@ -178,6 +247,54 @@ macro_rules! doc_set_tag {
     };
 }
 
+macro_rules! doc_map_tag {
+    () => {
+        "Applies `func` to the tag value and returns the pointer with a cleared \
+        tag together with the function's result."
+    };
+}
+
+macro_rules! doc_map_ptr {
+    () => {
+        "Applies `func` to the pointer stripped of its tag and returns the \
+        resulting pointer composed with the previous tag value.\n\n\
+        # Panics\n\n\
+        Panics in *debug builds only*, if the pointer returned by `func` has \
+        any bits set in the lower bits reserved for the tag value."
+    };
+}
+
+macro_rules! doc_byte_offset {
+    ($desc:literal, $method:literal) => {
+        concat!(
+            "Calculates the offset of the pointer stripped of its tag by ",
+            $desc,
+            " and returns the result composed with the previous tag value.\n\n\
+            # Safety\n\n\
+            The same safety requirements as for `<*mut T>::",
+            $method,
+            "` apply to the pointer stripped of its tag.\n\n\
+            # Panics\n\n\
+            Panics in *debug builds only*, if the resulting pointer has any bits \
+            set in the lower bits reserved for the tag value."
+        )
+    };
+}
+
+macro_rules! doc_wrapping_offset {
+    ($desc:literal) => {
+        concat!(
+            "Calculates the offset of the pointer stripped of its tag by ",
+            $desc,
+            " using wrapping arithmetic and returns the result composed with \
+            the previous tag value.\n\n\
+            # Panics\n\n\
+            Panics in *debug builds only*, if the resulting pointer has any bits \
+            set in the lower bits reserved for the tag value."
+        )
+    };
+}
+
 macro_rules! doc_update_tag {
     ("non-null" $example_type_path:path) => {
         concat!(
@@ -259,6 +376,120 @@ macro_rules! doc_decompose_tag {
     };
 }
 
+macro_rules! doc_pointer_eq {
+    () => {
+        "Returns `true` if both marked pointers point to the same address, \
+        regardless of their respective tag values."
+    };
+}
+
+macro_rules! doc_tag_eq {
+    () => {
+        "Returns `true` if both marked pointers have the same tag value, \
+        regardless of the addresses they point to."
+    };
+}
+
+macro_rules! doc_raw_access {
+    ($summary:expr, $fn_name:literal) => {
+        concat!(
+            $summary,
+            "\n\n# Safety\n\n\
+            See [`ptr::",
+            $fn_name,
+            "`][core::ptr::",
+            $fn_name,
+            "] for safety concerns and examples."
+        )
+    };
+}
+
+macro_rules! doc_read {
+    () => {
+        doc_raw_access!(
+            "Reads the value from the marked pointer without moving it, \
+            ignoring the tag value.",
+            "read"
+        )
+    };
+}
+
+macro_rules! doc_read_volatile {
+    () => {
+        doc_raw_access!(
+            "Performs a volatile read of the value from the marked pointer \
+            without moving it, ignoring the tag value.",
+            "read_volatile"
+        )
+    };
+}
+
+macro_rules! doc_write {
+    () => {
+        doc_raw_access!(
+            "Overwrites the memory location of the marked pointer with the \
+            given value without reading or dropping the old value, ignoring \
+            the tag value.",
+            "write"
+        )
+    };
+}
+
+macro_rules! doc_write_volatile {
+    () => {
+        doc_raw_access!(
+            "Performs a volatile write of the memory location of the marked \
+            pointer with the given value without reading or dropping the old \
+            value, ignoring the tag value.",
+            "write_volatile"
+        )
+    };
+}
+
+macro_rules! doc_copy_to {
+    () => {
+        doc_raw_access!(
+            "Copies `count * size_of::<T>()` bytes from the marked pointer to \
+            `dest`, ignoring the tag value.\n\n\
+            The source and destination may overlap.",
+            "copy"
+        )
+    };
+}
+
+macro_rules! doc_copy_to_nonoverlapping {
+    () => {
+        doc_raw_access!(
+            "Copies `count * size_of::<T>()` bytes from the marked pointer to \
+            `dest`, ignoring the tag value.\n\n\
+            The source and destination may *not* overlap.",
+            "copy_nonoverlapping"
+        )
+    };
+}
+
+macro_rules! doc_copy_from {
+    () => {
+        doc_raw_access!(
+            "Copies `count * size_of::<T>()` bytes from `src` to the marked \
+            pointer, ignoring the tag value.\n\n\
+            The source and destination may overlap.",
+            "copy"
+        )
+    };
+}
+
+macro_rules! doc_copy_from_nonoverlapping {
+    () => {
+        doc_raw_access!(
+            "Copies `count * size_of::<T>()` bytes from `src` to the marked \
+            pointer, ignoring the tag value.\n\n\
+            The source and destination may *not* overlap.",
+            "copy_nonoverlapping"
+        )
+    };
+}
+
 macro_rules! doc_as_ref_or_mut {
     ("safety") => {
         "When calling this method, you have to ensure that *either* the \
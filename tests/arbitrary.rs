@@ -0,0 +1,43 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+
+type TagNonNull = tagptr::TagNonNull<u64, 3>;
+type TagPtr = tagptr::TagPtr<u64, 3>;
+
+/// Returns a deterministic pseudo-random byte sequence with frequent zero bytes.
+fn bytes(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state & 0b11 == 0 {
+                0
+            } else {
+                state as u8
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn non_null_is_never_null() {
+    let data = bytes(4096);
+    let mut u = Unstructured::new(&data);
+    while let Ok(ptr) = TagNonNull::arbitrary(&mut u) {
+        assert_ne!(ptr.decompose_ptr() as usize, 0);
+    }
+}
+
+#[test]
+fn compose_decompose_round_trip() {
+    let data = bytes(4096);
+    let mut u = Unstructured::new(&data);
+    while !u.is_empty() {
+        let ptr = TagPtr::arbitrary(&mut u).unwrap();
+        let (raw, tag) = ptr.decompose();
+        assert_eq!(TagPtr::from_usize(raw as usize | tag), ptr);
+    }
+}
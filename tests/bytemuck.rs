@@ -0,0 +1,40 @@
+#![cfg(feature = "bytemuck")]
+
+use core::sync::atomic::Ordering;
+
+use bytemuck::{Zeroable, ZeroableInOption};
+
+type AtomicTagPtr = tagptr::AtomicTagPtr<u64, 3>;
+type TagNonNull = tagptr::TagNonNull<u64, 3>;
+type TagPtr = tagptr::TagPtr<u64, 3>;
+
+#[test]
+fn zeroed() {
+    assert_eq!(TagPtr::zeroed(), TagPtr::null());
+    assert!(AtomicTagPtr::zeroed().load(Ordering::Relaxed).is_null());
+    assert_eq!(<Option<TagNonNull>>::zeroed(), None);
+}
+
+#[test]
+fn zeroable_in_option() {
+    fn assert_zeroable_in_option<T: ZeroableInOption>() {}
+    assert_zeroable_in_option::<TagNonNull>();
+
+    let zeroed: [Option<TagNonNull>; 2] = Zeroable::zeroed();
+    assert_eq!(zeroed, [None, None]);
+}
+
+// `Pod` requires `bytemuck`'s `unsound_ptr_pod_impl` feature, which is only
+// enabled when explicitly opting in via `--cfg tagptr_unsound_ptr_pod`
+#[cfg(tagptr_unsound_ptr_pod)]
+#[test]
+fn cast_slice_round_trip() {
+    let (a, b) = (&mut 1, &mut 2);
+    let ptrs = [TagPtr::compose(a, 0b101), TagPtr::compose(b, 0b010), TagPtr::null()];
+
+    let addrs: &[usize] = bytemuck::cast_slice(&ptrs);
+    assert_eq!(addrs, &[ptrs[0].into_usize(), ptrs[1].into_usize(), 0]);
+
+    let cast: &[TagPtr] = bytemuck::cast_slice(addrs);
+    assert_eq!(cast, &ptrs);
+}
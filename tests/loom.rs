@@ -0,0 +1,77 @@
+#![cfg(feature = "loom")]
+
+use core::ptr::NonNull;
+
+use loom::{
+    sync::{
+        atomic::{AtomicUsize, Ordering::SeqCst},
+        Arc,
+    },
+    thread,
+};
+
+use tagptr::{loom::AtomicTagPtr, TagPtr};
+
+#[repr(align(8))]
+struct Node {
+    value: AtomicUsize,
+}
+
+impl Node {
+    fn new() -> Arc<Self> {
+        Arc::new(Self { value: AtomicUsize::new(0) })
+    }
+
+    fn as_ptr(node: &Arc<Self>) -> *mut Self {
+        NonNull::from(&**node).as_ptr()
+    }
+}
+
+/// Node `a` is removed, recycled (i.e., its value is changed) and re-inserted
+/// by one thread, while another thread tries to replace it based on its
+/// previously observed value.
+/// With `N` tag bits, every successful exchange increments the tag.
+fn recycle<const N: usize>() {
+    loom::model(|| {
+        let (a, b, c) = (Node::new(), Node::new(), Node::new());
+        let head = Arc::new(AtomicTagPtr::<Node, N>::new(TagPtr::new(Node::as_ptr(&a))));
+
+        let recycler = {
+            let (head, a, b) = (Arc::clone(&head), Arc::clone(&a), Arc::clone(&b));
+            thread::spawn(move || {
+                let curr = head.load(SeqCst);
+                if curr.decompose_ptr() != Node::as_ptr(&a) {
+                    return;
+                }
+
+                let new = TagPtr::new(Node::as_ptr(&b)).set_tag(curr.decompose_tag() + 1);
+                if head.compare_exchange(curr, new, (SeqCst, SeqCst)).is_ok() {
+                    a.value.store(1, SeqCst);
+                    let recycled = TagPtr::new(Node::as_ptr(&a)).set_tag(new.decompose_tag() + 1);
+                    let _ = head.compare_exchange(new, recycled, (SeqCst, SeqCst));
+                }
+            })
+        };
+
+        let curr = head.load(SeqCst);
+        let value = unsafe { (*curr.decompose_ptr()).value.load(SeqCst) };
+        let new = TagPtr::new(Node::as_ptr(&c)).set_tag(curr.decompose_tag() + 1);
+        if head.compare_exchange(curr, new, (SeqCst, SeqCst)).is_ok() {
+            let current_value = unsafe { (*curr.decompose_ptr()).value.load(SeqCst) };
+            assert_eq!(value, current_value, "ABA: replaced node was recycled in the meantime");
+        }
+
+        recycler.join().unwrap();
+    });
+}
+
+#[test]
+#[should_panic(expected = "ABA")]
+fn untagged_aba() {
+    recycle::<0>();
+}
+
+#[test]
+fn tagged_no_aba() {
+    recycle::<2>();
+}
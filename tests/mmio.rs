@@ -0,0 +1,18 @@
+#![cfg(feature = "mmio")]
+
+use core::sync::atomic::Ordering;
+
+type AtomicTagPtr = tagptr::AtomicTagPtr<u32, 2>;
+type TagPtr = tagptr::TagPtr<u32, 2>;
+
+#[test]
+fn volatile_matches_atomic() {
+    let register = AtomicTagPtr::null();
+    let buffer = &mut 0u32;
+
+    unsafe { register.volatile_store(TagPtr::compose(buffer, 0b11)) };
+    assert_eq!(register.load(Ordering::Relaxed), TagPtr::compose(buffer, 0b11));
+
+    register.store(TagPtr::null_with_tag(0b10), Ordering::Relaxed);
+    assert_eq!(unsafe { register.volatile_load() }, TagPtr::null_with_tag(0b10));
+}
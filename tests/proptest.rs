@@ -0,0 +1,61 @@
+#![cfg(feature = "proptest")]
+
+use core::mem;
+
+use proptest::prelude::*;
+
+use tagptr::Null;
+
+type TagNonNull = tagptr::TagNonNull<u64, 3>;
+type TagPtr = tagptr::TagPtr<u64, 3>;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10_000))]
+
+    #[test]
+    fn arbitrary_is_well_formed(ptr in any::<TagPtr>()) {
+        let (raw, tag) = ptr.decompose();
+        prop_assert_eq!(raw as usize % mem::align_of::<u64>(), 0);
+        prop_assert!(tag <= TagPtr::TAG_MASK);
+        prop_assert_eq!(TagPtr::compose(raw, tag), ptr);
+    }
+
+    #[test]
+    fn set_tag_preserves_ptr(ptr in any::<TagPtr>(), tag in any::<usize>()) {
+        let tagged = ptr.set_tag(tag);
+        prop_assert_eq!(tagged.decompose_ptr(), ptr.decompose_ptr());
+        prop_assert_eq!(tagged.decompose_tag(), tag & TagPtr::TAG_MASK);
+    }
+
+    #[test]
+    fn compose_decompose(ptr in any::<TagPtr>(), tag in any::<usize>()) {
+        let raw = ptr.decompose_ptr();
+        prop_assert_eq!(TagPtr::compose(raw, tag).decompose(), (raw, tag & TagPtr::TAG_MASK));
+    }
+
+    #[test]
+    fn clear_tag(ptr in any::<TagPtr>()) {
+        prop_assert_eq!(ptr.clear_tag().decompose_tag(), 0);
+        prop_assert_eq!(ptr.clear_tag().decompose_ptr(), ptr.decompose_ptr());
+    }
+
+    #[test]
+    fn usize_round_trip(ptr in any::<TagPtr>()) {
+        prop_assert_eq!(TagPtr::from_usize(ptr.into_usize()), ptr);
+    }
+
+    #[test]
+    fn non_null_is_well_formed(ptr in any::<TagNonNull>()) {
+        let (raw, tag) = ptr.decompose();
+        prop_assert_eq!(raw.as_ptr() as usize % mem::align_of::<u64>(), 0);
+        prop_assert!(tag <= TagNonNull::TAG_MASK);
+        prop_assert_eq!(TagNonNull::new(ptr.into_marked_ptr()), Ok(ptr));
+    }
+
+    #[test]
+    fn null_tag(null in any::<Null>()) {
+        let ptr = TagPtr::null_with_tag(null.0);
+        prop_assert!(ptr.is_null());
+        prop_assert_eq!(TagNonNull::new(ptr), Err(Null(null.0 & TagPtr::TAG_MASK)));
+    }
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+
+type TagPtr = tagptr::TagPtr<i32, 2>;
+
+#[test]
+fn tag_ptr_round_trip() {
+    let reference = &mut 1;
+    let raw = reference as *mut i32;
+
+    for ptr in [TagPtr::null(), TagPtr::null_with_tag(0b11), TagPtr::compose(raw, 0b10)] {
+        let bytes = rkyv::to_bytes::<Error>(&ptr).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<TagPtr>, Error>(&bytes).unwrap();
+        assert_eq!(archived.to_native(), ptr.into_usize() as u64);
+
+        let deserialized: TagPtr = rkyv::from_bytes::<TagPtr, Error>(&bytes).unwrap();
+        assert_eq!(deserialized, ptr);
+        assert_eq!(deserialized.decompose_tag(), ptr.decompose_tag());
+    }
+}
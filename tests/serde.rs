@@ -0,0 +1,45 @@
+#![cfg(feature = "serde")]
+
+use core::ptr::NonNull;
+
+use tagptr::Null;
+
+type TagPtr = tagptr::TagPtr<i32, 2>;
+type TagNonNull = tagptr::TagNonNull<i32, 2>;
+
+#[test]
+fn tag_ptr_round_trip() {
+    let reference = &mut 1;
+    let raw = reference as *mut i32;
+
+    for ptr in [TagPtr::null(), TagPtr::null_with_tag(0b11), TagPtr::compose(raw, 0b10)] {
+        let json = serde_json::to_string(&ptr).unwrap();
+        assert_eq!(serde_json::from_str::<TagPtr>(&json).unwrap(), ptr);
+    }
+
+    let json = serde_json::to_string(&TagPtr::compose(raw, 0b01)).unwrap();
+    assert_eq!(json, format!("[{},1]", raw as usize));
+}
+
+#[test]
+fn tag_ptr_invalid() {
+    assert!(serde_json::from_str::<TagPtr>("[1,0]").is_err());
+    assert!(serde_json::from_str::<TagPtr>("[0,4]").is_err());
+}
+
+#[test]
+fn tag_non_null_round_trip() {
+    let reference = &1;
+    let ptr = TagNonNull::compose(NonNull::from(reference), 0b11);
+
+    let json = serde_json::to_string(&ptr).unwrap();
+    assert_eq!(serde_json::from_str::<TagNonNull>(&json).unwrap(), ptr);
+    assert!(serde_json::from_str::<TagNonNull>("[0,3]").is_err());
+}
+
+#[test]
+fn null_round_trip() {
+    let json = serde_json::to_string(&Null(3)).unwrap();
+    assert_eq!(json, r#"{"tag":3}"#);
+    assert_eq!(serde_json::from_str::<Null>(&json).unwrap(), Null(3));
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "zerocopy")]
+
+use tagptr::Null;
+use zerocopy::{FromBytes, FromZeros, IntoBytes, TryFromBytes};
+
+type TagPtr = tagptr::TagPtr<u8, 1>;
+
+#[test]
+fn null_from_bytes() {
+    let bytes = 0b1usize.to_ne_bytes();
+    let null = Null::ref_from_bytes(&bytes[..]).unwrap();
+    assert_eq!(*null, Null(0b1));
+    assert_eq!(null.as_bytes(), &bytes[..]);
+}
+
+#[test]
+fn tag_ptr_from_zeros() {
+    assert_eq!(TagPtr::new_zeroed(), TagPtr::null());
+
+    let zeroed = [0u8; core::mem::size_of::<TagPtr>()];
+    assert_eq!(*TagPtr::try_ref_from_bytes(&zeroed[..]).unwrap(), TagPtr::null());
+
+    let tagged = 0b1usize.to_ne_bytes();
+    assert!(TagPtr::try_ref_from_bytes(&tagged[..]).is_err());
+}